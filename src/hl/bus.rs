@@ -0,0 +1,253 @@
+//! 
+//! The SPI bus abstraction for the high-level driver.
+//! 
+//! The [`DW3XXX`](super::DW3XXX) driver is written once against the [`Bus`] trait, whose methods are all async, and can then be
+//! used with either a blocking or an asynchronous SPI device:
+//! 
+//!  - [`Blocking`] wraps an [`embedded_hal::spi::SpiDevice`]. Every future it returns is ready the first time it is polled, so the
+//!    driver may be used from blocking code with [`block_on`](super::block_on).
+//!  - `Async` wraps an `embedded_hal_async::spi::SpiDevice` and is only available with the `async` feature enabled.
+//! 
+//! Because both variants share the same driver code, they behave identically for configuration, transmission, reception, and
+//! ranging. The delays used while the device resets, the PLL locks, and the receiver is calibrated follow the variant of the bus
+//! through [`BusDelay`].
+//! 
+
+use crate::ll::commands::Command;
+use crate::ll::reg::{Register, Writable};
+use crate::ll::spi::{self, AccessMode, CrcMode, IndirectAccess, IndirectPointer, MaskedFieldWrite, MaskedWriteMode, TransactionError};
+
+use super::SpiError;
+
+/// 
+/// A bus over which the driver communicates with the device.
+/// 
+/// The methods of this trait correspond to the transaction helper functions of the [`ll::spi`](crate::ll::spi) module, performed
+/// with the SPI CRC mode of the bus.
+/// 
+#[allow(async_fn_in_trait)]
+pub trait Bus {
+    /// The error type of the underlying SPI device.
+    type Error;
+
+    /// The SPI CRC mode used for transactions.
+    fn crc_mode(&self) -> CrcMode;
+
+    /// 
+    /// Sets the SPI CRC mode used for transactions.
+    /// 
+    /// This only changes how the bus performs transactions, see [`DW3XXX::set_crc_mode`](super::DW3XXX::set_crc_mode) for also
+    /// configuring the device.
+    /// 
+    fn set_crc_mode(&mut self, mode: CrcMode);
+
+    /// Execute a fast command.
+    async fn fast_command(&mut self, command: Command) -> Result<(), SpiError<Self::Error>>;
+
+    /// Perform a short addressed SPI transaction.
+    async fn short_addressed(&mut self, base_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>>;
+
+    /// Perform a full addressed SPI transaction.
+    async fn full_addressed(&mut self, base_address: u8, sub_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>>;
+
+    /// Perform a masked write SPI transaction.
+    async fn masked_write(&mut self, base_address: u8, sub_address: u8, mode: MaskedWriteMode, data: &[u8]) -> Result<(), SpiError<Self::Error>>;
+
+    /// Read an entire register from the device.
+    async fn read<R: Register>(&mut self) -> Result<R::RegisterValue, SpiError<Self::Error>>;
+
+    /// Write an entire register to the device.
+    async fn write<R: Register>(&mut self, value: R::RegisterValue) -> Result<(), SpiError<Self::Error>>;
+
+    /// Perform an SPI transaction through one of the indirect pointers.
+    async fn indirect_access(&mut self, access: IndirectAccess, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>>;
+
+    /// Write a single field of a register on the device with a masked write transaction.
+    async fn modify_field<F: Writable>(&mut self, value: F::Value) -> Result<(), SpiError<Self::Error>> {
+        let write = MaskedFieldWrite::new::<F>(value);
+
+        self.masked_write(F::Register::BASE_ADDRESS, write.sub_address(), write.mode(), write.masks()).await
+    }
+
+    /// 
+    /// Perform an SPI transaction on part of a register through one of the indirect pointers.
+    /// 
+    /// The offset is relative to the start of the register, and the access must lie entirely within the register.
+    /// 
+    async fn indirect<R: Register>(&mut self, pointer: IndirectPointer, offset: usize, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        let access = IndirectAccess::for_register::<R>(pointer, offset, data.len()).ok_or(SpiError::OutOfBounds)?;
+
+        self.indirect_access(access, mode, data).await
+    }
+}
+
+/// 
+/// A delay matching the variant of a [`Bus`], used while waiting for the device to reset, the PLL to lock, and the receiver to be
+/// calibrated.
+/// 
+/// With the [`Blocking`] bus this is any blocking [`embedded_hal::delay::DelayNs`]. With the `Async` bus this is any
+/// `embedded_hal_async::delay::DelayNs`, which is awaited such that other tasks may run while waiting.
+/// 
+/// ```rust
+/// # #[cfg(feature = "async")]
+/// # async fn example<SPI, DELAY>(spi: SPI, mut delay: DELAY) -> Result<(), dw3xxx::hl::InitError<SPI::Error>>
+/// # where SPI: embedded_hal_async::spi::SpiDevice, DELAY: embedded_hal_async::delay::DelayNs {
+/// # use dw3xxx::hl::{Async, DW3XXX};
+/// let mut dw3xxx = DW3XXX::new(Async::new(spi));
+/// 
+/// // Other tasks may run while the device wakes up, the PLL locks, and the receiver is calibrated.
+/// let device = dw3xxx.init(&mut delay).await?;
+/// # Ok(())
+/// # }
+/// ```
+/// 
+#[allow(async_fn_in_trait)]
+pub trait BusDelay<BUS> {
+    /// Delays for at least the given number of microseconds.
+    async fn delay_us(&mut self, us: u32);
+}
+
+/// A [`Bus`] built on a blocking [`embedded_hal::spi::SpiDevice`].
+pub struct Blocking<SPI> {
+    spi: SPI,
+    crc: CrcMode
+}
+
+impl<SPI> Blocking<SPI> {
+    /// Constructs a new instance of [`Blocking`].
+    pub fn new(spi: SPI) -> Self {
+        Self { spi, crc: CrcMode::Disabled }
+    }
+
+    /// Releases the underlying SPI device.
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+}
+
+impl<SPI: embedded_hal::spi::SpiDevice> Bus for Blocking<SPI> {
+    type Error = SPI::Error;
+
+    fn crc_mode(&self) -> CrcMode {
+        self.crc
+    }
+
+    fn set_crc_mode(&mut self, mode: CrcMode) {
+        self.crc = mode;
+    }
+
+    async fn fast_command(&mut self, command: Command) -> Result<(), SpiError<Self::Error>> {
+        spi::fast_command(&mut self.spi, command).map_err(SpiError::BusError)
+    }
+
+    async fn short_addressed(&mut self, base_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::short_addressed_crc(&mut self.spi, base_address, mode, self.crc, data)?)
+    }
+
+    async fn full_addressed(&mut self, base_address: u8, sub_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::full_addressed_crc(&mut self.spi, base_address, sub_address, mode, self.crc, data)?)
+    }
+
+    async fn masked_write(&mut self, base_address: u8, sub_address: u8, mode: MaskedWriteMode, data: &[u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::masked_write_crc(&mut self.spi, base_address, sub_address, mode, self.crc, data)?)
+    }
+
+    async fn read<R: Register>(&mut self) -> Result<R::RegisterValue, SpiError<Self::Error>> {
+        Ok(spi::read_crc::<R, _>(&mut self.spi, self.crc)?)
+    }
+
+    async fn write<R: Register>(&mut self, value: R::RegisterValue) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::write_crc::<R, _>(&mut self.spi, self.crc, value)?)
+    }
+
+    async fn indirect_access(&mut self, access: IndirectAccess, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::indirect_crc(&mut self.spi, access, mode, self.crc, data)?)
+    }
+}
+
+impl<SPI, DELAY: embedded_hal::delay::DelayNs> BusDelay<Blocking<SPI>> for DELAY {
+    async fn delay_us(&mut self, us: u32) {
+        embedded_hal::delay::DelayNs::delay_us(self, us);
+    }
+}
+
+/// 
+/// A [`Bus`] built on an asynchronous `embedded_hal_async::spi::SpiDevice`.
+/// 
+/// This is only available with the `async` feature enabled.
+/// 
+#[cfg(feature = "async")]
+pub struct Async<SPI> {
+    spi: SPI,
+    crc: CrcMode
+}
+
+#[cfg(feature = "async")]
+impl<SPI> Async<SPI> {
+    /// Constructs a new instance of [`Async`].
+    pub fn new(spi: SPI) -> Self {
+        Self { spi, crc: CrcMode::Disabled }
+    }
+
+    /// Releases the underlying SPI device.
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI: embedded_hal_async::spi::SpiDevice> Bus for Async<SPI> {
+    type Error = SPI::Error;
+
+    fn crc_mode(&self) -> CrcMode {
+        self.crc
+    }
+
+    fn set_crc_mode(&mut self, mode: CrcMode) {
+        self.crc = mode;
+    }
+
+    async fn fast_command(&mut self, command: Command) -> Result<(), SpiError<Self::Error>> {
+        spi::asynch::fast_command(&mut self.spi, command).await.map_err(SpiError::BusError)
+    }
+
+    async fn short_addressed(&mut self, base_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::asynch::short_addressed_crc(&mut self.spi, base_address, mode, self.crc, data).await?)
+    }
+
+    async fn full_addressed(&mut self, base_address: u8, sub_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::asynch::full_addressed_crc(&mut self.spi, base_address, sub_address, mode, self.crc, data).await?)
+    }
+
+    async fn masked_write(&mut self, base_address: u8, sub_address: u8, mode: MaskedWriteMode, data: &[u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::asynch::masked_write_crc(&mut self.spi, base_address, sub_address, mode, self.crc, data).await?)
+    }
+
+    async fn read<R: Register>(&mut self) -> Result<R::RegisterValue, SpiError<Self::Error>> {
+        Ok(spi::asynch::read_crc::<R, _>(&mut self.spi, self.crc).await?)
+    }
+
+    async fn write<R: Register>(&mut self, value: R::RegisterValue) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::asynch::write_crc::<R, _>(&mut self.spi, self.crc, value).await?)
+    }
+
+    async fn indirect_access(&mut self, access: IndirectAccess, mode: AccessMode, data: &mut [u8]) -> Result<(), SpiError<Self::Error>> {
+        Ok(spi::asynch::indirect_crc(&mut self.spi, access, mode, self.crc, data).await?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI, DELAY: embedded_hal_async::delay::DelayNs> BusDelay<Async<SPI>> for DELAY {
+    async fn delay_us(&mut self, us: u32) {
+        embedded_hal_async::delay::DelayNs::delay_us(self, us).await;
+    }
+}
+
+impl<E> From<TransactionError<E>> for SpiError<E> {
+    fn from(error: TransactionError<E>) -> Self {
+        match error {
+            TransactionError::BusError(error) => SpiError::BusError(error),
+            TransactionError::CrcError        => SpiError::CrcError,
+        }
+    }
+}
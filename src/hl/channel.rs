@@ -0,0 +1,202 @@
+//! 
+//! Switching between the UWB channels supported by the device.
+//! 
+//! See [`DW3XXX::set_channel`].
+//! 
+
+use crate::ll::reg::{
+    Channel, DgcCfg0Value, DgcCfg1Value, DgcLut0Value, DgcLut1Value, DgcLut2Value, DgcLut3Value, DgcLut4Value, DgcLut5Value,
+    DgcLut6Value, LdoRloadValue, Register, RfTxCtrl1Value, RfTxCtrl2Value, CHAN_CTRL, DGC_CFG, DGC_CFG0, DGC_CFG1, DGC_LUT_0, DGC_LUT_1,
+    DGC_LUT_2, DGC_LUT_3, DGC_LUT_4, DGC_LUT_5, DGC_LUT_6, LDO_RLOAD, RF_TX_CTRL_1, RF_TX_CTRL_2
+};
+
+use super::config::{prf, Prf};
+use super::{Bus, BusDelay, ConfigError, DW3XXX};
+
+/// The load on the LDOs, which is the same for both channels.
+const LDO_RLOAD_VALUE: u8 = 0x14;
+/// The low bits of the transmitter analog configuration, which are the same for both channels.
+const RF_TX_CTRL_1_VALUE: u8 = 0x0E;
+/// The DGC threshold used with a 64 MHz PRF.
+const DGC_THR_64: u8 = 0x32;
+/// The DGC configuration used with a 64 MHz PRF.
+const DGC_CFG0_VALUE: u32 = 0x1000_0240;
+/// The DGC configuration used with a 64 MHz PRF.
+const DGC_CFG1_VALUE: u32 = 0x1B6D_A489;
+
+/// 
+/// The recommended analog tuning of a channel.
+/// 
+/// This follows the channel dependent writes of `dwt_configure` in the manufacturer's DW3000 API, which neither writes `PLL_CC` nor
+/// `LDO_CTRL` when the channel changes:
+/// 
+///  - `PLL_CC` holds a separate coarse code for each channel, which is the result of the PLL calibration rather than a tuning
+///    value. The calibration started by [`lock_pll`](DW3XXX::lock_pll) leaves `USE_OLD` of `PLL_CAL` clear, so the coarse code of
+///    the new channel is searched for from scratch and written to `PLL_CC` by the device, and the code of the other channel is left
+///    untouched.
+///  - The LDOs are configured the same way for both channels, with their load written to `LDO_RLOAD`. `LDO_CTRL` is only changed
+///    during the receiver calibration, as by `dwt_run_pgfcal`, which restores it afterwards.
+/// 
+struct ChannelTuning {
+    /// The value of the `RF_TX_CTRL_2` register.
+    rf_tx_ctrl: u32,
+    /// The value of the `PLL_CFG` register.
+    pll_cfg: u16,
+    /// The values of the `DGC_LUT_0` to `DGC_LUT_6` registers, used with a 64 MHz PRF.
+    dgc_lut: [u32; 7]
+}
+
+/// The tuning of channel 5.
+const CHANNEL_5: ChannelTuning = ChannelTuning {
+    rf_tx_ctrl: 0x1C07_1134,
+    pll_cfg: 0x1F3C,
+    dgc_lut: [0x1C0FD, 0x1C43E, 0x1C6BE, 0x1C77E, 0x1CF36, 0x1CFB5, 0x1CFF5]
+};
+
+/// The tuning of channel 9.
+const CHANNEL_9: ChannelTuning = ChannelTuning {
+    rf_tx_ctrl: 0x1C01_0034,
+    pll_cfg: 0x0F3C,
+    dgc_lut: [0x2A8FE, 0x2AC36, 0x2A5FE, 0x2AF3E, 0x2AF7D, 0x2AFB5, 0x2AFB5]
+};
+
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// 
+    /// Switches both the transmitter and the receiver to another channel, keeping the rest of the configuration.
+    /// 
+    /// The recommended tuning of the channel is written to `RF_TX_CTRL_2` and `PLL_CFG`, after which the PLL is calibrated again
+    /// with `PLL_CAL` and the driver waits for the `CPLOCK` event. The PLL keeps a separate coarse code for each channel in
+    /// `PLL_CC`, which the calibration writes itself, so the calibration of one channel is not disturbed by switching to the other.
+    /// When using a 64 MHz PRF the DGC tables of the channel are loaded, and the receiver is then calibrated again. As in the
+    /// manufacturer's DW3000 API, neither `PLL_CC` nor `LDO_CTRL` is written with a value for the channel, while `LDO_CTRL` is
+    /// restored after the receiver calibration:
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+    /// # use dw3xxx::hl::{block_on, Blocking, DW3XXX};
+    /// # use dw3xxx::ll::{reg::Channel, spi::{self, AccessMode}};
+    /// # fn read(base: u8, sub: u8, data: &[u8]) -> [Transaction<u8>; 4] {
+    /// #     let header = match sub {
+    /// #         0 => spi::short_addressed_header(base, AccessMode::Read).to_vec(),
+    /// #         _ => spi::full_addressed_header(base, sub, AccessMode::Read).to_vec(),
+    /// #     };
+    /// #     [Transaction::transaction_start(), Transaction::write_vec(header), Transaction::read_vec(data.to_vec()), Transaction::transaction_end()]
+    /// # }
+    /// # fn write(base: u8, sub: u8, data: &[u8]) -> [Transaction<u8>; 4] {
+    /// #     let header = match sub {
+    /// #         0 => spi::short_addressed_header(base, AccessMode::Write).to_vec(),
+    /// #         _ => spi::full_addressed_header(base, sub, AccessMode::Write).to_vec(),
+    /// #     };
+    /// #     [Transaction::transaction_start(), Transaction::write_vec(header), Transaction::write_vec(data.to_vec()), Transaction::transaction_end()]
+    /// # }
+    /// # let spi = Mock::new(&[
+    /// #     read(0x01, 0x14, &[0x18, 0x03]),                          // CHAN_CTRL, channel 5 with a 16 MHz PRF
+    /// #     write(0x01, 0x14, &[0x19, 0x03]),
+    /// #     write(0x07, 0x1C, &[0x34, 0x00, 0x01, 0x1C]),             // RF_TX_CTRL_2
+    /// #     write(0x07, 0x51, &[0x14]),                               // LDO_RLOAD
+    /// #     write(0x07, 0x1A, &[0x0E]),                               // RF_TX_CTRL_1
+    /// #     read(0x11, 0x04, &[0x00, 0x02, 0x30, 0x00]),              // CLK_CTRL
+    /// #     write(0x11, 0x04, &[0x03, 0x02, 0x30, 0x00]),
+    /// #     write(0x09, 0x00, &[0x3C, 0x0F]),                         // PLL_CFG
+    /// #     write(0x09, 0x08, &[0x80, 0x01]),                         // PLL_CAL, without USE_OLD
+    /// #     write(0x00, 0x44, &[0x02, 0x00, 0x00, 0x02, 0x00, 0x00]), // SYS_STATUS
+    /// #     read(0x11, 0x04, &[0x03, 0x02, 0x30, 0x00]),              // CLK_CTRL
+    /// #     write(0x11, 0x04, &[0x00, 0x02, 0x30, 0x00]),
+    /// #     read(0x11, 0x08, &[0x38, 0x01, 0x00, 0x00]),              // SEQ_CTRL
+    /// #     write(0x11, 0x08, &[0x38, 0x01, 0x00, 0x00]),
+    /// #     read(0x00, 0x44, &[0x02, 0x00, 0x00, 0x00, 0x00, 0x00]),  // SYS_STATUS, CPLOCK
+    /// #     read(0x03, 0x18, &[0x01, 0x64]),                          // DGC_CFG
+    /// #     write(0x03, 0x18, &[0x00, 0x64]),
+    /// #     read(0x07, 0x48, &[0x00, 0x10, 0x00, 0x00]),              // LDO_CTRL
+    /// #     write(0x07, 0x48, &[0x05, 0x11, 0x00, 0x00]),
+    /// #     write(0x04, 0x0C, &[0x01, 0x00, 0x02, 0x00]),             // RX_CAL
+    /// #     read(0x04, 0x0C, &[0x01, 0x00, 0x02, 0x00]),
+    /// #     write(0x04, 0x0C, &[0x11, 0x00, 0x02, 0x00]),
+    /// #     read(0x04, 0x20, &[0x01]),                                // RX_CAL_STS
+    /// #     write(0x04, 0x0C, &[0x00, 0x00, 0x00, 0x00]),             // RX_CAL
+    /// #     write(0x04, 0x20, &[0x01]),                               // RX_CAL_STS
+    /// #     read(0x04, 0x0C, &[0x00, 0x00, 0x00, 0x00]),              // RX_CAL
+    /// #     write(0x04, 0x0C, &[0x00, 0x00, 0x01, 0x00]),
+    /// #     read(0x04, 0x14, &[0x00, 0x10, 0x00, 0x00]),              // RX_CAL_RESI
+    /// #     read(0x04, 0x1C, &[0x00, 0x10, 0x00, 0x00]),              // RX_CAL_RESQ
+    /// #     write(0x07, 0x48, &[0x00, 0x10, 0x00, 0x00]),             // LDO_CTRL, restored
+    /// # ].concat());
+    /// # let mut delay = NoopDelay::new();
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi));
+    /// 
+    /// block_on(dw3xxx.set_channel(Channel::Channel9, &mut delay))?;
+    /// # dw3xxx.decompose().0.release().done();
+    /// # Ok::<(), dw3xxx::hl::ConfigError<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    /// 
+    /// The delay bounds the time allowed for the PLL to lock and the receiver to be calibrated, as with [`init`](DW3XXX::init).
+    /// 
+    /// The device must be idle and must have been initialised with [`init`](DW3XXX::init). If the tuning fails, for example as the
+    /// PLL fails to lock or raises the `PLL_HILO` event, `CHAN_CTRL` is restored to the previous channel and the error is returned,
+    /// such as [`ConfigError::PllLock`]. The analog tuning is then left incomplete, so the device should be switched back with
+    /// [`set_channel`](DW3XXX::set_channel) or reset.
+    /// 
+    pub async fn set_channel(&mut self, channel: Channel, delay: &mut impl BusDelay<BUS>) -> Result<(), ConfigError<BUS::Error>> {
+        let previous = self.read::<CHAN_CTRL>().await?;
+        let mut chan_ctrl = previous;
+        chan_ctrl.set_rf_chan(channel);
+        self.write::<CHAN_CTRL>(chan_ctrl).await?;
+
+        if let Err(error) = self.tune_channel(channel, prf(chan_ctrl.rx_pcode()) == Some(Prf::Prf64), delay).await {
+            // Keep CHAN_CTRL in agreement with the configuration kept by the driver. The tuning error is more useful than an error
+            // from restoring the channel, so the latter is ignored.
+            let _ = self.write::<CHAN_CTRL>(previous).await;
+
+            return Err(error);
+        }
+
+        if let Some(config) = &mut self.config {
+            config.channel = channel;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the tuning of a channel, locks the PLL, and calibrates the receiver.
+    pub(super) async fn tune_channel(
+        &mut self,
+        channel: Channel,
+        prf_64: bool,
+        delay: &mut impl BusDelay<BUS>
+    ) -> Result<(), ConfigError<BUS::Error>> {
+        let tuning = match channel {
+            Channel::Channel5 => &CHANNEL_5,
+            Channel::Channel9 => &CHANNEL_9,
+        };
+
+        self.write::<RF_TX_CTRL_2>(*RfTxCtrl2Value::from(RF_TX_CTRL_2::ZEROED).set_value(tuning.rf_tx_ctrl)).await?;
+        self.write::<LDO_RLOAD>(*LdoRloadValue::from(LDO_RLOAD::ZEROED).set_value(LDO_RLOAD_VALUE)).await?;
+        self.write::<RF_TX_CTRL_1>(*RfTxCtrl1Value::from(RF_TX_CTRL_1::ZEROED).set_value(RF_TX_CTRL_1_VALUE)).await?;
+
+        if !self.lock_pll(tuning.pll_cfg, delay).await? {
+            return Err(ConfigError::PllLock);
+        }
+
+        if prf_64 {
+            self.write::<DGC_LUT_0>(*DgcLut0Value::from(DGC_LUT_0::ZEROED).set_value(tuning.dgc_lut[0])).await?;
+            self.write::<DGC_LUT_1>(*DgcLut1Value::from(DGC_LUT_1::ZEROED).set_value(tuning.dgc_lut[1])).await?;
+            self.write::<DGC_LUT_2>(*DgcLut2Value::from(DGC_LUT_2::ZEROED).set_value(tuning.dgc_lut[2])).await?;
+            self.write::<DGC_LUT_3>(*DgcLut3Value::from(DGC_LUT_3::ZEROED).set_value(tuning.dgc_lut[3])).await?;
+            self.write::<DGC_LUT_4>(*DgcLut4Value::from(DGC_LUT_4::ZEROED).set_value(tuning.dgc_lut[4])).await?;
+            self.write::<DGC_LUT_5>(*DgcLut5Value::from(DGC_LUT_5::ZEROED).set_value(tuning.dgc_lut[5])).await?;
+            self.write::<DGC_LUT_6>(*DgcLut6Value::from(DGC_LUT_6::ZEROED).set_value(tuning.dgc_lut[6])).await?;
+            self.write::<DGC_CFG0>(*DgcCfg0Value::from(DGC_CFG0::ZEROED).set_value(DGC_CFG0_VALUE)).await?;
+            self.write::<DGC_CFG1>(*DgcCfg1Value::from(DGC_CFG1::ZEROED).set_value(DGC_CFG1_VALUE)).await?;
+
+            self.modify::<DGC_CFG>(|r| r.set_rx_tune_en(true).set_thr_64(DGC_THR_64)).await?;
+        } else {
+            self.modify::<DGC_CFG>(|r| r.set_rx_tune_en(false)).await?;
+        }
+
+        if !self.calibrate_receiver(delay).await? {
+            return Err(ConfigError::RxCalibration);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+//! 
+//! The configuration of the PHY.
+//! 
+//! See [`Config`] and [`DW3XXX::apply_config`].
+//! 
+
+use crate::ll::reg::{
+    Channel, DataRate, Dtune3Value, PacSize, PhrMode, PreambleLength, RxSfdTocValue, SfdType, StsPacketConfig, CHAN_CTRL, DTUNE0,
+    DTUNE3, RX_SFD_TOC, SYS_CFG, TX_FCTRL
+};
+
+use super::{Bus, BusDelay, SpiError, DW3XXX};
+
+/// The preamble detection threshold used when frames carry a PHR and payload.
+const PD_THRESH_DEFAULT: u32 = 0xAF5F_584C;
+/// The preamble detection threshold used with [`StsPacketConfig::Sp3`], where frames carry no PHR or payload.
+const PD_THRESH_NO_DATA: u32 = 0xAF5F_35CC;
+
+/// 
+/// The configuration of the PHY, applied to the device with [`apply_config`](DW3XXX::apply_config).
+/// 
+/// The [`Default`] configuration uses channel 5, a 128 symbol preamble with preamble code 9 (64 MHz PRF), the IEEE 802.15.4a SFD,
+/// and a data rate of 6.8 Mb/s with a standard PHR, which are the settings commonly used by examples and other devices.
+/// 
+/// ```rust
+/// # use dw3xxx::hl::{Config, InvalidConfig};
+/// # use dw3xxx::ll::reg::{Channel, PacSize, PreambleLength};
+/// let mut config = Config { channel: Channel::Channel9, preamble_length: PreambleLength::Symbols32, ..Config::default() };
+/// assert_eq!(config.validate(), Err(InvalidConfig::PacSize));
+/// 
+/// config.pac_size = PacSize::Pac4;
+/// config.sfd_timeout = config.recommended_sfd_timeout();
+/// assert_eq!(config.validate(), Ok(()));
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// The channel used by both the transmitter and the receiver.
+    pub channel: Channel,
+    /// The length of the transmitted preamble.
+    pub preamble_length: PreambleLength,
+    /// The preamble acquisition chunk size used by the receiver, which should suit the length of the preamble.
+    pub pac_size: PacSize,
+    /// The preamble code used by the transmitter, which also selects the PRF.
+    pub tx_preamble_code: u8,
+    /// The preamble code used by the receiver, which also selects the PRF.
+    pub rx_preamble_code: u8,
+    /// The start of frame delimiter.
+    pub sfd_type: SfdType,
+    /// The data rate of the payload.
+    pub data_rate: DataRate,
+    /// The mode of the PHR, which limits the length of frames.
+    pub phr_mode: PhrMode,
+    /// The data rate of the PHR, which may only be 6.8 Mb/s when the data rate of the payload is also 6.8 Mb/s.
+    pub phr_rate: DataRate,
+    /// The position of the STS within frames.
+    pub sts_mode: StsPacketConfig,
+    /// The SFD timeout in preamble symbols, which may not be zero.
+    pub sfd_timeout: u16
+}
+
+impl Config {
+    /// 
+    /// Checks the configuration for illegal combinations, returning the first that is found.
+    /// 
+    /// Preamble codes 3 and 4 use a 16 MHz PRF, codes 9 to 12 use a 64 MHz PRF, and codes 25 to 32 are the SCP codes, all of which
+    /// may be used on both channel 5 and channel 9. The transmitter and receiver codes must use the same PRF.
+    /// 
+    pub fn validate(&self) -> Result<(), InvalidConfig> {
+        let tx_prf = prf(self.tx_preamble_code).ok_or(InvalidConfig::PreambleCode(self.tx_preamble_code))?;
+        let rx_prf = prf(self.rx_preamble_code).ok_or(InvalidConfig::PreambleCode(self.rx_preamble_code))?;
+
+        if tx_prf != rx_prf {
+            return Err(InvalidConfig::PrfMismatch);
+        }
+
+        if pac_symbols(self.pac_size) > preamble_symbols(self.preamble_length) / 8 {
+            return Err(InvalidConfig::PacSize);
+        }
+
+        if self.phr_rate == DataRate::Rate6M8 && self.data_rate != DataRate::Rate6M8 {
+            return Err(InvalidConfig::PhrRate);
+        }
+
+        if self.sts_mode != StsPacketConfig::Off && self.sfd_type != SfdType::Ieee4z {
+            return Err(InvalidConfig::StsSfdType);
+        }
+
+        if self.sfd_timeout == 0 {
+            return Err(InvalidConfig::SfdTimeout);
+        }
+
+        Ok(())
+    }
+
+    /// 
+    /// Returns the recommended SFD timeout for the preamble length, SFD and PAC size of the configuration.
+    /// 
+    /// This is the length of the preamble, plus one, plus the length of the SFD, less the PAC size.
+    /// 
+    pub fn recommended_sfd_timeout(&self) -> u16 {
+        preamble_symbols(self.preamble_length) + 1 + sfd_symbols(self.sfd_type) - pac_symbols(self.pac_size)
+    }
+
+    /// Returns whether the configuration uses a 64 MHz PRF.
+    fn is_prf_64(&self) -> bool {
+        prf(self.rx_preamble_code) == Some(Prf::Prf64)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            channel: Channel::Channel5,
+            preamble_length: PreambleLength::Symbols128,
+            pac_size: PacSize::Pac8,
+            tx_preamble_code: 9,
+            rx_preamble_code: 9,
+            sfd_type: SfdType::Ieee4a,
+            data_rate: DataRate::Rate6M8,
+            phr_mode: PhrMode::Standard,
+            phr_rate: DataRate::Rate850K,
+            sts_mode: StsPacketConfig::Off,
+            sfd_timeout: 129
+        }
+    }
+}
+
+/// The pulse repetition frequency selected by a preamble code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Prf {
+    Prf16,
+    Prf64,
+    Scp
+}
+
+/// Returns the PRF selected by a preamble code, or [`None`] if the code is not supported.
+pub(super) fn prf(code: u8) -> Option<Prf> {
+    match code {
+        3 | 4    => Some(Prf::Prf16),
+        9..=12   => Some(Prf::Prf64),
+        25..=32  => Some(Prf::Scp),
+        _        => None,
+    }
+}
+
+/// Returns the number of symbols in a preamble.
+fn preamble_symbols(length: PreambleLength) -> u16 {
+    match length {
+        PreambleLength::Symbols32   => 32,
+        PreambleLength::Symbols64   => 64,
+        PreambleLength::Symbols128  => 128,
+        PreambleLength::Symbols256  => 256,
+        PreambleLength::Symbols512  => 512,
+        PreambleLength::Symbols1024 => 1024,
+        PreambleLength::Symbols1536 => 1536,
+        PreambleLength::Symbols2048 => 2048,
+        PreambleLength::Symbols4096 => 4096,
+    }
+}
+
+/// Returns the number of symbols in a preamble acquisition chunk.
+pub(super) fn pac_symbols(size: PacSize) -> u16 {
+    match size {
+        PacSize::Pac4  => 4,
+        PacSize::Pac8  => 8,
+        PacSize::Pac16 => 16,
+        PacSize::Pac32 => 32,
+    }
+}
+
+/// Returns the preamble detection threshold for the STS mode, as chosen by `dwt_configure` in the manufacturer's DW3000 API.
+fn pd_threshold(sts_mode: StsPacketConfig) -> u32 {
+    match sts_mode {
+        StsPacketConfig::Sp3 => PD_THRESH_NO_DATA,
+        _                    => PD_THRESH_DEFAULT,
+    }
+}
+
+/// Returns the number of symbols in an SFD.
+fn sfd_symbols(sfd: SfdType) -> u16 {
+    match sfd {
+        SfdType::Decawave16 => 16,
+        _                   => 8,
+    }
+}
+
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// 
+    /// Validates and applies a configuration of the PHY.
+    /// 
+    /// The configuration is written in the order recommended by the manufacturer:
+    /// 
+    ///  1. The PHR mode and rate, and the STS mode in `SYS_CFG`, and the preamble detection threshold for the STS mode in `DTUNE3`.
+    ///  2. The channel, SFD, and preamble codes in `CHAN_CTRL`.
+    ///  3. The data rate and preamble length in `TX_FCTRL`.
+    ///  4. The SFD timeout in `RX_SFD_TOC`.
+    ///  5. The tuning of the channel, as described by [`set_channel`](DW3XXX::set_channel).
+    ///  6. The PAC size in `DTUNE0`.
+    /// 
+    /// The delay bounds the time allowed for the PLL to lock and the receiver to be calibrated, as with [`init`](DW3XXX::init).
+    /// 
+    /// The device must have been initialised with [`init`](DW3XXX::init). Once applied, the configuration is kept by the driver and
+    /// applied again after a [`soft_reset`](DW3XXX::soft_reset) or [`hard_reset`](DW3XXX::hard_reset).
+    /// 
+    pub async fn apply_config(&mut self, config: Config, delay: &mut impl BusDelay<BUS>) -> Result<(), ConfigError<BUS::Error>> {
+        config.validate().map_err(ConfigError::Invalid)?;
+
+        self.modify::<SYS_CFG>(|r| r
+            .set_phr_mode(config.phr_mode)
+            .set_phr_6m8(config.phr_rate == DataRate::Rate6M8)
+            .set_cp_spc(config.sts_mode)
+        ).await?;
+        self.write::<DTUNE3>(*Dtune3Value::default().set_value(pd_threshold(config.sts_mode))).await?;
+
+        self.modify::<CHAN_CTRL>(|r| r
+            .set_rf_chan(config.channel)
+            .set_sfd_type(config.sfd_type)
+            .set_tx_pcode(config.tx_preamble_code)
+            .set_rx_pcode(config.rx_preamble_code)
+        ).await?;
+
+        self.modify::<TX_FCTRL>(|r| r
+            .set_txbr(config.data_rate)
+            .set_txpsr(config.preamble_length)
+            .set_fine_plen(0)
+        ).await?;
+
+        self.write::<RX_SFD_TOC>(*RxSfdTocValue::default().set_value(config.sfd_timeout)).await?;
+
+        self.tune_channel(config.channel, config.is_prf_64(), delay).await?;
+
+        self.modify::<DTUNE0>(|r| r.set_pac(config.pac_size)).await?;
+
+        self.config = Some(config);
+
+        Ok(())
+    }
+
+    /// Returns the configuration that was last applied with [`apply_config`](DW3XXX::apply_config).
+    pub fn config(&self) -> Option<&Config> {
+        self.config.as_ref()
+    }
+}
+
+/// An illegal combination of settings in a [`Config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidConfig {
+    /// The preamble code is not supported.
+    PreambleCode(u8),
+    /// The preamble codes of the transmitter and receiver use different PRFs.
+    PrfMismatch,
+    /// The PAC size is too large for the length of the preamble.
+    PacSize,
+    /// The PHR rate is 6.8 Mb/s while the data rate is 850 kb/s.
+    PhrRate,
+    /// The STS is enabled without the IEEE 802.15.4z SFD.
+    StsSfdType,
+    /// The SFD timeout is zero.
+    SfdTimeout
+}
+
+/// An error resulting from the [`apply_config`](DW3XXX::apply_config) and [`set_channel`](DW3XXX::set_channel) methods.
+#[derive(Debug)]
+pub enum ConfigError<E> {
+    /// The configuration is invalid.
+    /// 
+    /// See [`InvalidConfig`].
+    Invalid(InvalidConfig),
+    /// One of the SPI related errors.
+    /// 
+    /// See [`SpiError`].
+    SpiError(SpiError<E>),
+    /// The PLL did not lock after the channel was tuned, or raised the `PLL_HILO` event while locking.
+    PllLock,
+    /// The receiver calibration did not complete, or produced an invalid result.
+    RxCalibration
+}
+
+impl<E> From<SpiError<E>> for ConfigError<E> {
+    fn from(error: SpiError<E>) -> Self {
+        ConfigError::SpiError(error)
+    }
+}
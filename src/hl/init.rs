@@ -0,0 +1,325 @@
+//! 
+//! The device initialisation sequence.
+//! 
+//! See [`DW3XXX::init`].
+//! 
+
+use embedded_hal::digital::ErrorKind;
+
+use crate::ll::interrupts::Interrupt;
+use crate::ll::reg::{
+    DevIdValue, OtpAddrValue, OtpCfgValue, PllCalValue, PllCfgValue, Register, RxCalStsValue, RxCalValue, SysClk, XtalValue,
+    BIAS_CTRL, CLK_CTRL, DEV_ID, LDO_CTRL, OTP_ADDR, OTP_CFG, OTP_RDATA, PLL_CAL, PLL_CFG, RX_CAL, RX_CAL_RESI, RX_CAL_RESQ,
+    RX_CAL_STS, SEQ_CTRL, XTAL
+};
+
+use super::{Bus, BusDelay, ConfigError, SpiError, DW3XXX};
+
+/// The OTP address of the low word of the LDO tuning calibration.
+const OTP_LDOTUNE_LO: u16 = 0x04;
+/// The OTP address of the high word of the LDO tuning calibration.
+const OTP_LDOTUNE_HI: u16 = 0x05;
+/// The OTP address of the bias tuning calibration.
+const OTP_BIASTUNE: u16 = 0x0A;
+/// The OTP address of the crystal trim calibration.
+const OTP_XTAL_TRIM: u16 = 0x1E;
+/// The OTP address of the DGC tuning calibration.
+const OTP_DGC_TUNE: u16 = 0x20;
+
+/// The value of the DGC tuning calibration when the RX_TUNE parameters have been programmed into OTP.
+const DGC_TUNE_PROGRAMMED: u32 = 0x1000_0240;
+/// The crystal trim used when none has been programmed into OTP.
+const DEFAULT_XTAL_TRIM: u8 = 0x2E;
+/// The PLL configuration for channel 5, which is the channel selected at reset.
+const PLL_CFG_CH5: u16 = 0x1F3C;
+/// The LDOs that must be enabled while running the RX calibration.
+const RX_CAL_LDO_ENABLE: u16 = 0x0105;
+/// The value of the RX calibration results when the calibration has failed.
+const RX_CAL_FAILED: u32 = 0x1FFF_FFFF;
+
+/// The interval in microseconds between reads of a status register while waiting for the device.
+const POLL_INTERVAL_US: u32 = 20;
+/// The time in microseconds allowed for the device to enter the `IDLE_RC` state.
+const READY_TIMEOUT_US: u32 = 5000;
+/// The time in microseconds allowed for the PLL to lock.
+const PLL_LOCK_TIMEOUT_US: u32 = 1000;
+/// The time in microseconds allowed for the RX calibration to complete.
+const RX_CAL_TIMEOUT_US: u32 = 1000;
+
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// 
+    /// Initialises the device, returning the identity of the device.
+    /// 
+    /// This performs the following steps, returning an [`InitError`] describing the step that failed:
+    /// 
+    ///  1. Waits for the `RCINIT` and `SPIRDY` events, indicating that the device has entered the `IDLE_RC` state.
+    ///  2. Reads `DEV_ID` and checks that the device is a known DW3000, DW3110 or DW3120 (see [`DeviceId::from_dev_id`]).
+    ///  3. Loads the LDO, bias, and DGC calibration from OTP when present, and trims the crystal.
+    ///  4. Switches the system clock to the PLL and waits for the PLL to lock.
+    ///  5. Runs the receiver calibration.
+    /// 
+    /// The delay is used to wait between reads of the status registers while the device becomes ready, the PLL locks, and the
+    /// receiver is calibrated, such that the time allowed for each is bounded. It is a [`BusDelay`] of the same variant as the bus,
+    /// so with the `Async` bus the delays are awaited. Once complete, the device is in the `IDLE_PLL` state and ready to be
+    /// configured.
+    /// 
+    pub async fn init(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<DeviceId, InitError<BUS::Error>> {
+        self.wait_until_ready(delay).await?;
+
+        let dev_id = self.read::<DEV_ID>().await.map_err(InitError::spi(InitStep::DeviceId))?;
+        let device = DeviceId::from_dev_id(dev_id).ok_or(InitError::UnknownDevice(dev_id))?;
+
+        self.load_otp_calibration().await.map_err(InitError::spi(InitStep::Otp))?;
+
+        if !self.lock_pll(PLL_CFG_CH5, delay).await.map_err(InitError::spi(InitStep::Pll))? {
+            return Err(InitError::PllLock);
+        }
+
+        if !self.calibrate_receiver(delay).await.map_err(InitError::spi(InitStep::RxCalibration))? {
+            return Err(InitError::RxCalibration);
+        }
+
+        Ok(device)
+    }
+
+    /// 
+    /// Reads a 32-bit word from the OTP memory of the device.
+    /// 
+    /// The address is the index of the word in OTP memory, not the index of an octet.
+    /// 
+    pub async fn read_otp(&mut self, address: u16) -> Result<u32, SpiError<BUS::Error>> {
+        self.write::<OTP_CFG>(*OtpCfgValue::from(OTP_CFG::ZEROED).set_otp_man(true)).await?;
+        self.write::<OTP_ADDR>(*OtpAddrValue::default().set_value(address)).await?;
+        self.write::<OTP_CFG>(*OtpCfgValue::from(OTP_CFG::ZEROED).set_otp_read(true)).await?;
+
+        Ok(self.read::<OTP_RDATA>().await?.value())
+    }
+
+    /// 
+    /// Waits until both the `RCINIT` and `SPIRDY` events are pending.
+    /// 
+    /// The `SYS_STATUS` register is polled every 20 µs for up to 5 ms. The IRQ line is not awaited, as it is never asserted by a
+    /// device that is unpowered or has already had its events cleared, which would leave the wait unbounded.
+    /// 
+    async fn wait_until_ready(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<(), InitError<BUS::Error>> {
+        let ready = Interrupt::Rcinit | Interrupt::Spirdy;
+
+        for _ in 0..READY_TIMEOUT_US.div_ceil(POLL_INTERVAL_US) {
+            delay.delay_us(POLL_INTERVAL_US).await;
+
+            let status = self.interrupt_status().await.map_err(InitError::spi(InitStep::WakeUp))?;
+
+            if status.contains_all(ready) {
+                self.clear_interrupt_set(ready).await.map_err(InitError::spi(InitStep::WakeUp))?;
+
+                return Ok(());
+            }
+        }
+
+        Err(InitError::NotReady)
+    }
+
+    /// Loads the calibration parameters that have been programmed into OTP memory.
+    async fn load_otp_calibration(&mut self) -> Result<(), SpiError<BUS::Error>> {
+        let ldo_tune_lo = self.read_otp(OTP_LDOTUNE_LO).await?;
+        let ldo_tune_hi = self.read_otp(OTP_LDOTUNE_HI).await?;
+        let bias_tune = ((self.read_otp(OTP_BIASTUNE).await? >> 16) & 0x1F) as u16;
+
+        if ldo_tune_lo != 0 && ldo_tune_hi != 0 && bias_tune != 0 {
+            self.modify::<OTP_CFG>(|r| r.set_ldo_kick(true).set_bias_kick(true)).await?;
+            self.modify::<BIAS_CTRL>(|r| r.set_value((r.value() & !0x1F) | bias_tune)).await?;
+        }
+
+        // The DGC_SEL bit selects the RX_TUNE parameters for channel 5, which is the channel selected at reset.
+        if self.read_otp(OTP_DGC_TUNE).await? == DGC_TUNE_PROGRAMMED {
+            self.modify::<OTP_CFG>(|r| r.set_dgc_kick(true).set_dgc_sel(false)).await?;
+        }
+
+        let xtal_trim = match (self.read_otp(OTP_XTAL_TRIM).await? & 0x7F) as u8 {
+            0    => DEFAULT_XTAL_TRIM,
+            trim => trim,
+        };
+        self.write::<XTAL>(*XtalValue::from(XTAL::ZEROED).set_value(xtal_trim)).await
+    }
+
+    /// 
+    /// Switches the system clock to the PLL with the given PLL configuration, returning whether the PLL locked.
+    /// 
+    /// The system clock is first forced to the FOSC clock, as the `CPLOCK` event may not be raised again while the PLL is running,
+    /// so this also calibrates the PLL again when the system clock is already the PLL. The `CPLOCK` event is polled every 20 µs for
+    /// up to 1 ms.
+    /// 
+    /// `PLL_CAL` is written with the extended lock delay of the manufacturer's `RF_PLL_CFG_LD` in `PLL_CFG_LD`, but `USE_OLD` is
+    /// deliberately left clear. Starting from the coarse code kept in `PLL_CC` only helps when locking the same channel again, while
+    /// after a channel switch it would start the calibration from a stale or never calibrated code, so the calibration always
+    /// searches for the coarse code from scratch. The low bit of the manufacturer's value of `0x81` is not a field of the register
+    /// map, and is not written.
+    /// 
+    pub(super) async fn lock_pll(&mut self, pll_cfg: u16, delay: &mut impl BusDelay<BUS>) -> Result<bool, SpiError<BUS::Error>> {
+        self.modify::<CLK_CTRL>(|r| r.set_sys_clk(SysClk::Fosc)).await?;
+
+        self.write::<PLL_CFG>(*PllCfgValue::default().set_value(pll_cfg)).await?;
+        self.write::<PLL_CAL>(*PllCalValue::from(PLL_CAL::ZEROED).set_pll_cfg_ld(0x8).set_cal_en(true)).await?;
+
+        self.clear_interrupt_set(Interrupt::Cplock | Interrupt::PllHilo).await?;
+        self.modify::<CLK_CTRL>(|r| r.set_sys_clk(SysClk::Auto)).await?;
+        self.modify::<SEQ_CTRL>(|r| r.set_ainit2idle(true)).await?;
+
+        for _ in 0..PLL_LOCK_TIMEOUT_US.div_ceil(POLL_INTERVAL_US) {
+            delay.delay_us(POLL_INTERVAL_US).await;
+
+            let status = self.interrupt_status().await?;
+
+            // The PLL_HILO event indicates that the calibration ran out of range, so the PLL will not lock.
+            if status.contains(Interrupt::PllHilo) {
+                return Ok(false);
+            }
+
+            if status.contains(Interrupt::Cplock) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// 
+    /// Runs the receiver calibration, returning whether the calibration succeeded.
+    /// 
+    /// The `RX_CAL_STS` register is polled every 20 µs for up to 1 ms.
+    /// 
+    pub(super) async fn calibrate_receiver(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<bool, SpiError<BUS::Error>> {
+        let ldo_ctrl = self.read::<LDO_CTRL>().await?;
+        let mut enabled = ldo_ctrl;
+        enabled.set_low(ldo_ctrl.low() | RX_CAL_LDO_ENABLE);
+        self.write::<LDO_CTRL>(enabled).await?;
+
+        self.write::<RX_CAL>(*RxCalValue::from(RX_CAL::ZEROED).set_comp_dly(0x2).set_cal_mode(0x1)).await?;
+        self.modify::<RX_CAL>(|r| r.set_cal_en(0x1)).await?;
+
+        let mut complete = false;
+        for _ in 0..RX_CAL_TIMEOUT_US.div_ceil(POLL_INTERVAL_US) {
+            delay.delay_us(POLL_INTERVAL_US).await;
+
+            if self.read::<RX_CAL_STS>().await?.value() {
+                complete = true;
+                break;
+            }
+        }
+
+        // Return the calibration block to normal mode, clear the status, and enable reading the results.
+        self.write::<RX_CAL>(RxCalValue::from(RX_CAL::ZEROED)).await?;
+        self.write::<RX_CAL_STS>(*RxCalStsValue::default().set_value(true)).await?;
+        self.modify::<RX_CAL>(|r| r.set_comp_dly(0x1)).await?;
+
+        let resi = self.read::<RX_CAL_RESI>().await?.value();
+        let resq = self.read::<RX_CAL_RESQ>().await?.value();
+
+        self.write::<LDO_CTRL>(ldo_ctrl).await?;
+
+        Ok(complete && resi != RX_CAL_FAILED && resq != RX_CAL_FAILED)
+    }
+}
+
+/// The identity of a DW3XXX device, as read from the `DEV_ID` register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceId {
+    /// The model of the device.
+    pub model: DeviceModel,
+    /// The silicon revision of the device, which is 1 for the B0 revision and 2 for the C0 revision.
+    pub revision: u8
+}
+
+impl DeviceId {
+    /// 
+    /// Identifies a device from the value of its `DEV_ID` register, returning [`None`] for unknown devices.
+    /// 
+    /// The DW3000 and the DW3110 are the same single antenna part, so both are identified as [`DeviceModel::Dw3110`]. Only the B0 and
+    /// C0 silicon revisions are known, and any other revision is rejected as an unknown device, as the tuning values used by the
+    /// driver are specific to these revisions.
+    /// 
+    /// ```rust
+    /// # use dw3xxx::hl::{DeviceId, DeviceModel};
+    /// # use dw3xxx::ll::reg::DevIdValue;
+    /// let device = DeviceId::from_dev_id(DevIdValue::from(0xDECA_0312_u32.to_le_bytes()));
+    /// assert_eq!(device, Some(DeviceId { model: DeviceModel::Dw3120, revision: 2 }));
+    /// 
+    /// // A DW3000 C0 reports the same identity as a DW3110.
+    /// let device = DeviceId::from_dev_id(DevIdValue::from(0xDECA_0302_u32.to_le_bytes()));
+    /// assert_eq!(device, Some(DeviceId { model: DeviceModel::Dw3110, revision: 2 }));
+    /// 
+    /// // Unknown silicon revisions are rejected.
+    /// assert_eq!(DeviceId::from_dev_id(DevIdValue::from(0xDECA_0303_u32.to_le_bytes())), None);
+    /// ```
+    /// 
+    pub fn from_dev_id(value: DevIdValue) -> Option<DeviceId> {
+        if value.ridtag() != 0xDECA || value.model() != 0x03 {
+            return None;
+        }
+
+        let model = match value.ver() {
+            0 => DeviceModel::Dw3110,
+            1 => DeviceModel::Dw3120,
+            _ => return None,
+        };
+
+        match value.rev() {
+            revision @ (1 | 2) => Some(DeviceId { model, revision }),
+            _                  => None,
+        }
+    }
+}
+
+/// A known model of the DW3000 family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceModel {
+    /// The single antenna DW3110, which is also sold as the DW3000 and reports the same `DEV_ID`.
+    Dw3110,
+    /// The dual antenna DW3120, with support for PDoA.
+    Dw3120
+}
+
+/// A step of the initialisation sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitStep {
+    /// Waiting for the device to enter the `IDLE_RC` state.
+    WakeUp,
+    /// Identifying the device.
+    DeviceId,
+    /// Loading the calibration from OTP memory.
+    Otp,
+    /// Locking the PLL.
+    Pll,
+    /// Calibrating the receiver.
+    RxCalibration,
+    /// Resetting the device before it is initialised again, see [`soft_reset`](DW3XXX::soft_reset).
+    SoftReset
+}
+
+/// An error resulting from the [`init`](DW3XXX::init) method.
+#[derive(Debug)]
+pub enum InitError<E> {
+    /// An SPI error during one of the steps of the initialisation sequence.
+    SpiError(InitStep, SpiError<E>),
+    /// An error from the reset pin while driving the `RSTn` line.
+    ResetPinError(ErrorKind),
+    /// The device did not raise the `RCINIT` and `SPIRDY` events in time.
+    NotReady,
+    /// The `DEV_ID` register does not belong to a known device.
+    UnknownDevice(DevIdValue),
+    /// The PLL did not lock.
+    PllLock,
+    /// The receiver calibration did not complete, or produced an invalid result.
+    RxCalibration,
+    /// The configuration kept by the driver could not be applied again after a reset.
+    /// 
+    /// See [`ConfigError`].
+    ConfigError(ConfigError<E>)
+}
+
+impl<E> InitError<E> {
+    /// Returns a function that tags an [`SpiError`] with the step during which it occurred.
+    pub(super) fn spi(step: InitStep) -> impl FnOnce(SpiError<E>) -> InitError<E> {
+        move |error| InitError::SpiError(step, error)
+    }
+}
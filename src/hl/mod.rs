@@ -1,113 +1,980 @@
 //! The high-level interface for the DW3XXX
 //! 
+//! # Blocking and Async
+//! 
+//! The [`DW3XXX`] driver is generic over a [`Bus`], and all of its methods are async. With the [`Blocking`] bus every future
+//! completes the first time it is polled, so blocking firmware can simply drive each call to completion with [`block_on`]:
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! use dw3xxx::hl::{block_on, Blocking, DW3XXX};
+//! use dw3xxx::ll::reg::DEV_ID;
+//! # let spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b00000000]),
+//! #     Transaction::read_vec(vec![0x02, 0x03, 0xCA, 0xDE]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! 
+//! let mut dw3xxx = DW3XXX::new(Blocking::new(spi));
+//! 
+//! let dev_id = block_on(dw3xxx.read::<DEV_ID>())?;
+//! assert_eq!(dev_id.ridtag(), 0xDECA);
+//! # dw3xxx.decompose().0.release().done();
+//! # Ok::<(), dw3xxx::hl::SpiError<embedded_hal::spi::ErrorKind>>(())
+//! ```
+//! 
+//! With the `async` feature enabled, the `Async` bus may instead be used with an `embedded_hal_async::spi::SpiDevice`, and the
+//! methods awaited from an async executor such as Embassy or RTIC 2.
+//! 
+//! # Waiting for Events
+//! 
+//! Methods that wait for the device to complete an operation, such as [`receive`](DW3XXX::receive) and
+//! [`transmit`](DW3XXX::transmit), are only available once the driver has been given an [`EventWait`] with
+//! [`with_event_wait`](DW3XXX::with_event_wait). Boards without the IRQ line connected may use [`Polling`] with the [`Blocking`] bus
+//! to check for events at a fixed interval. With the `async` feature enabled, `AsyncPolling` does the same without blocking the
+//! executor, and the `IrqPin` event wait awaits the IRQ line of the device:
+//! 
+//! ```rust
+//! # #[cfg(feature = "async")]
+//! # async fn example<SPI, IRQ>(spi: SPI, irq: IRQ)
+//! # where SPI: embedded_hal_async::spi::SpiDevice, IRQ: embedded_hal_async::digital::Wait {
+//! # use dw3xxx::hl::{Async, CrcStatus, IrqPin, RxTimeouts, DW3XXX};
+//! let mut dw3xxx = DW3XXX::new(Async::new(spi)).with_event_wait(IrqPin::new(irq));
+//! let mut buffer = [0; 127];
+//! 
+//! match dw3xxx.receive(&mut buffer, RxTimeouts::default()).await {
+//!     Ok(frame) if frame.crc() == CrcStatus::Ok => { /* ... */ },
+//!     Ok(frame) => { /* ... */ },
+//!     Err(error) => { /* ... */ },
+//! }
+//! # }
+//! ```
+//! 
 
-/// High-level driver for the DW3XXX
-pub struct DW3XXX {
+mod bus;
+mod channel;
+mod config;
+mod init;
+mod reset;
+mod timeout;
+mod wait;
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::ll::commands::Command;
+use crate::ll::interrupts::{FastInterrupt, Interrupt, InterruptSet};
+use crate::ll::reg::{
+    sys_status, DataRate, Field, PhrMode, PulseRepetitionFrequency, Register, ReservedValue, RxFinfoValue, RxPreambleLength,
+    Writable, FINT_STAT, RX_BUFFER_0, RX_FINFO, RX_TIME, SYS_CFG, SYS_ENABLE, SYS_STATUS, TX_BUFFER, TX_FCTRL, TX_TIME
+};
+use crate::ll::spi::{AccessMode, CrcMode, IndirectPointer};
+
+pub use bus::{Bus, BusDelay, Blocking};
+pub use config::{Config, ConfigError, InvalidConfig};
+pub use init::{DeviceId, DeviceModel, InitError, InitStep};
+pub use reset::{OpenDrainPin, ResetBlock, ResetPin};
+pub use timeout::RxTimeouts;
+#[cfg(feature = "async")]
+pub use bus::Async;
+pub use wait::{EventWait, Polling};
+#[cfg(feature = "async")]
+pub use wait::{AsyncPolling, IrqPin};
+
+/// 
+/// Drives a future to completion by repeatedly polling it.
+/// 
+/// This is intended for using the [`DW3XXX`] driver with a [`Blocking`] bus, for which every future completes the first time it is
+/// polled. It will busy-wait on any future that is not immediately ready, so it should not be used with the `Async` bus.
+/// 
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
 
+/// High-level driver for the DW3XXX
+pub struct DW3XXX<BUS, WAIT = (), RESET = ()> {
+    bus: BUS,
+    wait: WAIT,
+    reset: RESET,
+    config: Option<Config>
 }
 
-impl DW3XXX {
+impl<BUS: Bus> DW3XXX<BUS> {
     /// Constructs a new instance of [`DW3XXX`].
-    pub fn new() -> Self {
-        todo!()
+    pub fn new(bus: BUS) -> Self {
+        Self { bus, wait: (), reset: (), config: None }
     }
+}
 
-    /// Decomposes an instance of [`DW3XXX`].
-    pub fn decompose(self) -> () {
-        todo!()
-    } 
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// Sets the [`EventWait`] used to wait for the device to complete operations.
+    pub fn with_event_wait<W: EventWait>(self, wait: W) -> DW3XXX<BUS, W, RESET> {
+        DW3XXX { bus: self.bus, wait, reset: self.reset, config: self.config }
+    }
 
-    /// Clears all interrupts.
-    pub async fn clear_interrupts(&mut self) -> Result<(), FastCommandError> {
-        // Coverage for CLR_IRQS
+    /// 
+    /// Sets the [`ResetPin`] driving the `RSTn` line of the device, used by [`hard_reset`](DW3XXX::hard_reset).
+    /// 
+    /// The `RSTn` line must never be driven high by the host, so an [`OutputPin`](embedded_hal::digital::OutputPin) must be
+    /// configured as an open-drain output and wrapped in an [`OpenDrainPin`].
+    /// 
+    pub fn with_reset_pin<P: ResetPin>(self, reset: P) -> DW3XXX<BUS, WAIT, P> {
+        DW3XXX { bus: self.bus, wait: self.wait, reset, config: self.config }
+    }
 
-        todo!()
+    /// Decomposes an instance of [`DW3XXX`] into the bus, the event wait, and the reset pin.
+    pub fn decompose(self) -> (BUS, WAIT, RESET) {
+        (self.bus, self.wait, self.reset)
     }
 
-    /// Toggles the double buffer pointer.
-    pub async fn toggle_buffer(&mut self) -> Result<(), FastCommandError> {
-        // Coverage for DB_TOGGLE
+    /// Reads an entire register from the device.
+    pub async fn read<R: Register>(&mut self) -> Result<R::RegisterValue, SpiError<BUS::Error>> {
+        self.bus.read::<R>().await
+    }
 
-        todo!()
+    /// Writes an entire register to the device.
+    pub async fn write<R: Register>(&mut self, value: R::RegisterValue) -> Result<(), SpiError<BUS::Error>> {
+        self.bus.write::<R>(value).await
     }
 
-    /// Forces the device back into the idle state.
-    pub async fn force_idle(&mut self) -> Result<(), FastCommandError> {
-        // Coverage for TXRXOFF
+    /// Modifies a register on the device with a single read and a single write.
+    pub async fn modify<R: Register>(
+        &mut self,
+        f: impl FnOnce(&mut R::RegisterValue) -> &mut R::RegisterValue,
+    ) -> Result<(), SpiError<BUS::Error>> {
+        let mut value = self.read::<R>().await?;
 
-        todo!()
+        f(&mut value);
+
+        self.write::<R>(value).await
+    }
+
+    /// 
+    /// Writes a single field of a register on the device.
+    /// 
+    /// Unlike [`modify`](DW3XXX::modify) the register is not read, instead the device applies an AND and OR mask to the register
+    /// itself with a single masked write transaction, which avoids racing against the device's own updates to the other bits of
+    /// the register.
+    /// 
+    pub async fn modify_field<F: Writable>(&mut self, value: F::Value) -> Result<(), SpiError<BUS::Error>> {
+        self.bus.modify_field::<F>(value).await
+    }
+
+    /// 
+    /// Reads part of a register through one of the indirect pointers, starting at the offset into the register.
+    /// 
+    /// This allows reading any part of a large register such as `ACC_MEM`, whereas full addressed transactions are limited to the
+    /// first 128 octets of a register file. Returns [`SpiError::OutOfBounds`] if the read would extend beyond the end of the register.
+    /// 
+    pub async fn read_indirect<R: Register>(&mut self, pointer: IndirectPointer, offset: usize, data: &mut [u8]) -> Result<(), SpiError<BUS::Error>> {
+        self.bus.indirect::<R>(pointer, offset, AccessMode::Read, data).await
+    }
+
+    /// 
+    /// Writes part of a register through one of the indirect pointers, starting at the offset into the register.
+    /// 
+    /// See [`read_indirect`](DW3XXX::read_indirect).
+    /// 
+    pub async fn write_indirect<R: Register>(&mut self, pointer: IndirectPointer, offset: usize, data: &mut [u8]) -> Result<(), SpiError<BUS::Error>> {
+        self.bus.indirect::<R>(pointer, offset, AccessMode::Write, data).await
+    }
+
+    /// 
+    /// Writes a frame into `TX_BUFFER` and programs `TX_FCTRL` to send it, without starting the transmission.
+    /// 
+    /// This allows a frame to be sent with one of the delayed transmissions, while [`transmit`](DW3XXX::transmit) and
+    /// [`transmit_receive`](DW3XXX::transmit_receive) write the frame themselves.
+    /// 
+    /// Unless the automatic FCS is disabled with the `DIS_FCS_TX` bit of `SYS_CFG`, the frame excludes the two octet FCS appended by the
+    /// device, which is included in `TXFLEN`. The frame length may not exceed 127 octets with the standard PHR, or 1023 octets with
+    /// the extended PHR, and the frame must fit within `TX_BUFFER` from the [`buffer_offset`](TxOptions::buffer_offset), otherwise
+    /// [`TransmitCommandError::FrameLength`] is returned. The offset must also lie within `TX_BUFFER`, even for an empty frame, as
+    /// it would otherwise be truncated by the 10-bit `TXB_OFFSET` field. The preamble length of the configuration applied with
+    /// [`apply_config`](DW3XXX::apply_config) is written to `TXPSR`.
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    /// # use dw3xxx::hl::{block_on, Blocking, TransmitCommandError, TxOptions, DW3XXX};
+    /// # use dw3xxx::ll::spi::{self, AccessMode};
+    /// # let spi = Mock::new(&[
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x10, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// # ]);
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi));
+    /// 
+    /// // TX_BUFFER is 1024 octets long, so an offset of 1024 lies beyond its end.
+    /// let options = TxOptions { buffer_offset: 1024, ..TxOptions::default() };
+    /// 
+    /// assert!(matches!(block_on(dw3xxx.write_tx_frame(&[], options)), Err(TransmitCommandError::FrameLength)));
+    /// # dw3xxx.decompose().0.release().done();
+    /// ```
+    /// 
+    pub async fn write_tx_frame(&mut self, frame: &[u8], options: TxOptions) -> Result<(), TransmitCommandError<BUS::Error>> {
+        let sys_cfg = self.read::<SYS_CFG>().await?;
+
+        let fcs_len = if sys_cfg.dis_fcs_tx() { 0 } else { FCS_LEN };
+        let max_len = match sys_cfg.phr_mode() {
+            Ok(PhrMode::Extended) => MAX_EXTENDED_FRAME_LEN,
+            _                     => MAX_STANDARD_FRAME_LEN,
+        };
+
+        let len = frame.len() + fcs_len;
+        let offset = options.buffer_offset as usize;
+        if len > max_len || offset >= TX_BUFFER::LEN || offset + frame.len() > TX_BUFFER::LEN {
+            return Err(TransmitCommandError::FrameLength);
+        }
+
+        let mut chunk = [0; TX_CHUNK_LEN];
+        for (index, data) in frame.chunks(TX_CHUNK_LEN).enumerate() {
+            let chunk = &mut chunk[..data.len()];
+            chunk.copy_from_slice(data);
+
+            // Full addressed transactions can only start within the first 128 octets, beyond which the indirect pointer is used.
+            match offset + index * TX_CHUNK_LEN {
+                0 => self.bus.short_addressed(TX_BUFFER::BASE_ADDRESS, AccessMode::Write, chunk).await?,
+                sub @ 1..=0x7F => self.bus.full_addressed(TX_BUFFER::BASE_ADDRESS, sub as u8, AccessMode::Write, chunk).await?,
+                start => self.bus.indirect::<TX_BUFFER>(IndirectPointer::A, start, AccessMode::Write, chunk).await?,
+            }
+        }
+
+        let preamble_length = self.config.map(|config| config.preamble_length);
+
+        self.modify::<TX_FCTRL>(|r| {
+            if let Some(preamble_length) = preamble_length {
+                r.set_txpsr(preamble_length);
+            }
+
+            r.set_txflen(len as u16).set_tr(options.ranging).set_txb_offset(options.buffer_offset)
+        }).await?;
+
+        Ok(())
+    }
+
+    /// 
+    /// Sets the SPI CRC mode of both the device and the bus.
+    /// 
+    /// When enabled, a CRC-8 is appended to every write transaction, and with [`CrcMode::WriteRead`] every read transaction is
+    /// checked against the `SPI_RD_CRC` register, with any mismatch resulting in [`SpiError::CrcError`]. CRC errors in write
+    /// transactions are instead detected by the device and reported with the `SPICRCE` event.
+    /// 
+    pub async fn set_crc_mode(&mut self, mode: CrcMode) -> Result<(), SpiError<BUS::Error>> {
+        // The write to SYS_CFG must use the CRC mode the device is in before the write takes effect.
+        match mode {
+            CrcMode::Disabled => {
+                self.modify::<SYS_CFG>(|r| r.set_spi_crcen(false)).await?;
+                self.bus.set_crc_mode(mode);
+            },
+            CrcMode::Write | CrcMode::WriteRead => {
+                if self.bus.crc_mode() == CrcMode::Disabled {
+                    self.modify::<SYS_CFG>(|r| r.set_spi_crcen(true)).await?;
+                }
+                self.bus.set_crc_mode(mode);
+            },
+        }
+
+        Ok(())
+    }
+
+    /// 
+    /// Executes a fast command, confirming that a delayed command was accepted by the device.
+    /// 
+    /// After issuing a [delayed command](Command::is_delayed) the `CMD_ERR` event of `SYS_STATUS` is checked, which the device raises
+    /// when a delayed command is rejected because it was issued too late, such that the start time in `DX_TIME` has already passed.
+    /// If set, the event is cleared and [`FastCommandError::FastCommandError`] is returned, such that the command may be scheduled
+    /// again with a later start time.
+    /// 
+    /// Other commands, including [`Command::Tx`] and [`Command::Rx`], are issued without the additional read of `SYS_STATUS`, as with
+    /// [`execute_unchecked`](DW3XXX::execute_unchecked), and are therefore never confirmed.
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    /// # use dw3xxx::hl::{block_on, Blocking, DW3XXX, FastCommandError};
+    /// # use dw3xxx::ll::{commands::Command, spi::{self, AccessMode}};
+    /// # let spi = Mock::new(&[
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::fast_command_header(Command::Dtx).to_vec()),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x49, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x01]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x49, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x01]),
+    /// #     Transaction::transaction_end(),
+    /// # ]);
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi));
+    /// 
+    /// match block_on(dw3xxx.execute(Command::Dtx)) {
+    ///     Ok(()) => { /* The transmission will start at DX_TIME */ },
+    ///     Err(FastCommandError::FastCommandError) => { /* The command was rejected, back off and retry */ },
+    ///     Err(error) => { /* ... */ },
+    /// }
+    /// # dw3xxx.decompose().0.release().done();
+    /// ```
+    /// 
+    pub async fn execute(&mut self, command: Command) -> Result<(), FastCommandError<BUS::Error>> {
+        self.execute_unchecked(command).await?;
+
+        if !command.is_delayed() {
+            return Ok(());
+        }
+
+        // Only the octet of SYS_STATUS holding CMD_ERR is read, rather than the entire register.
+        let sub_address = SYS_STATUS::SUB_ADDRESS + (sys_status::CMD_ERR::FIRST_BIT / 8) as u8;
+        let mask = 1 << (sys_status::CMD_ERR::FIRST_BIT % 8);
+
+        let mut status = [0];
+        self.bus.full_addressed(SYS_STATUS::BASE_ADDRESS, sub_address, AccessMode::Read, &mut status).await?;
+
+        if status[0] & mask != 0 {
+            // The event bits are cleared by writing a one, so the other events in the octet are left untouched.
+            self.bus.full_addressed(SYS_STATUS::BASE_ADDRESS, sub_address, AccessMode::Write, &mut [mask]).await?;
+
+            return Err(FastCommandError::FastCommandError);
+        }
+
+        Ok(())
+    }
+
+    /// 
+    /// Executes a fast command without confirming that it was accepted by the device.
+    /// 
+    /// See [`execute`](DW3XXX::execute).
+    /// 
+    pub async fn execute_unchecked(&mut self, command: Command) -> Result<(), SpiError<BUS::Error>> {
+        self.bus.fast_command(command).await
+    }
+
+    /// Reads the pending events of the `SYS_STATUS` register.
+    pub async fn interrupt_status(&mut self) -> Result<InterruptSet, SpiError<BUS::Error>> {
+        Ok(self.read::<SYS_STATUS>().await?.into())
+    }
+
+    /// Clears exactly the given events of the `SYS_STATUS` register.
+    pub async fn clear_interrupt_set(&mut self, interrupts: InterruptSet) -> Result<(), SpiError<BUS::Error>> {
+        self.write::<SYS_STATUS>(interrupts.into()).await
+    }
+
+    /// Reads the interrupts enabled in the `SYS_ENABLE` register.
+    pub async fn enabled_interrupts(&mut self) -> Result<InterruptSet, SpiError<BUS::Error>> {
+        Ok(self.read::<SYS_ENABLE>().await?.into())
+    }
+
+    /// Enables exactly the given interrupts in the `SYS_ENABLE` register, such that they assert the IRQ line.
+    pub async fn enable_interrupts(&mut self, interrupts: InterruptSet) -> Result<(), SpiError<BUS::Error>> {
+        self.write::<SYS_ENABLE>(interrupts.into()).await
+    }
+
+    /// 
+    /// Enables the given interrupts in addition to those already enabled in the `SYS_ENABLE` register, returning the interrupts
+    /// that were enabled before, such that they can be restored with [`enable_interrupts`](DW3XXX::enable_interrupts).
+    /// 
+    pub async fn add_interrupts(&mut self, interrupts: InterruptSet) -> Result<InterruptSet, SpiError<BUS::Error>> {
+        let enabled = self.enabled_interrupts().await?;
+        self.enable_interrupts(enabled | interrupts).await?;
+
+        Ok(enabled)
+    }
+
+    /// 
+    /// Services pending interrupts, intended to be called first in an interrupt service routine.
+    /// 
+    /// The one octet `FINT_STAT` register is read first, and only the part of `SYS_STATUS` holding the events of the categories that
+    /// are set is then read. The pending events of those categories are cleared, and returned as [`InterruptEvents`], which may be
+    /// iterated over to dispatch each [`InterruptEvent`].
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    /// # use dw3xxx::hl::{block_on, Blocking, DW3XXX, InterruptEvent};
+    /// # use dw3xxx::ll::{interrupts::Interrupt, spi::{self, AccessMode}};
+    /// # let spi = Mock::new(&[
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::short_addressed_header(0x1F, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0b0000_0001]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0xF3]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0xF0]),
+    /// #     Transaction::transaction_end(),
+    /// # ]);
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi));
+    /// 
+    /// for event in block_on(dw3xxx.service_interrupts())? {
+    ///     match event {
+    ///         InterruptEvent::TxOk(events) => assert!(events.contains(Interrupt::Txfrs)),
+    ///         InterruptEvent::RxOk(_) => { /* Read the received frame */ },
+    ///         InterruptEvent::SysPanic(events) => { /* Recover from the errors */ },
+    ///         _ => {},
+    ///     }
+    /// }
+    /// # dw3xxx.decompose().0.release().done();
+    /// # Ok::<(), dw3xxx::hl::SpiError<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    /// 
+    pub async fn service_interrupts(&mut self) -> Result<InterruptEvents, SpiError<BUS::Error>> {
+        let fint_stat = self.read::<FINT_STAT>().await?;
+
+        let categories = FastInterrupt::iter_set(fint_stat)
+            .fold(InterruptSet::EMPTY, |set, category| set | category.events());
+
+        if categories.is_empty() {
+            return Ok(InterruptEvents { categories: fint_stat.as_ref()[0], events: InterruptSet::EMPTY });
+        }
+
+        // Only the octets of SYS_STATUS spanning the events of the set categories are read and cleared.
+        let first = categories.bits().trailing_zeros() as usize / 8;
+        let last = (63 - categories.bits().leading_zeros()) as usize / 8;
+        let sub_address = SYS_STATUS::SUB_ADDRESS + first as u8;
+
+        let mut view = [0; 6];
+        self.bus.full_addressed(SYS_STATUS::BASE_ADDRESS, sub_address, AccessMode::Read, &mut view[first..=last]).await?;
+
+        let events = InterruptSet::from_view(&view).intersection(categories);
+
+        // The event bits are cleared by writing a one, so only the handled events are cleared.
+        let mut clear = events.to_view();
+        self.bus.full_addressed(SYS_STATUS::BASE_ADDRESS, sub_address, AccessMode::Write, &mut clear[first..=last]).await?;
+
+        Ok(InterruptEvents { categories: fint_stat.as_ref()[0], events })
+    }
+
+    /// Clears all interrupts.
+    pub async fn clear_interrupts(&mut self) -> Result<(), FastCommandError<BUS::Error>> {
+        self.execute(Command::ClrIrqs).await
+    }
+
+    /// Toggles the double buffer pointer.
+    pub async fn toggle_buffer(&mut self) -> Result<(), FastCommandError<BUS::Error>> {
+        self.execute(Command::DbToggle).await
+    }
+
+    /// Forces the device back into the idle state.
+    pub async fn force_idle(&mut self) -> Result<(), FastCommandError<BUS::Error>> {
+        self.execute(Command::TxRxOff).await
     }
+}
 
+impl<BUS: Bus, WAIT: EventWait, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// 
     /// Immediately receives.
-    pub async fn receive(&mut self) -> Result<ReceiverFrame, ReceiveCommandError> {
-        // Coverage for RX
+    /// 
+    /// The receiver events are first cleared, such that events left pending by an earlier operation are not mistaken for those of
+    /// this reception. Only the events that finish the reception are then enabled in `SYS_ENABLE`, such that a pending unrelated
+    /// event cannot assert the IRQ line, and they are awaited with the [`EventWait`], after which they are cleared and mapped to a
+    /// [`ReceiverFrame`] or [`ReceiverError`]. The interrupts that were enabled before are then restored.
+    /// 
+    /// The payload of a received frame is copied from `RX_BUFFER_0` into the buffer, excluding the FCS, and is truncated if the
+    /// buffer is too short (see [`ReceiverFrame::is_truncated`]). Double buffering must be disabled with the `DIS_DRXB` bit of
+    /// `SYS_CFG`, as it is at reset.
+    /// 
+    /// The timeouts are programmed before the receiver is enabled, and expire with [`ReceiverError::FrameTimeout`] or
+    /// [`ReceiverError::PreambleTimeout`].
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+    /// # use core::time::Duration;
+    /// # use dw3xxx::hl::{block_on, Blocking, CrcStatus, Polling, RxTimeouts, DW3XXX};
+    /// # use dw3xxx::ll::{commands::Command, reg::PulseRepetitionFrequency, spi::{self, AccessMode, MaskedWriteMode}};
+    /// # let spi = Mock::new(&[
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x34, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x27, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::masked_write_header(0x00, 0x11, MaskedWriteMode::EightBit).to_vec()),
+    /// #     Transaction::write_vec(vec![0xFD, 0x02]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x06, 0x04, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0xFF, 0x37, 0x34, 0x02, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0xD0, 0x37, 0x34, 0x02, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::fast_command_header(Command::Rx).to_vec()),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x6F, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0xFF, 0x37, 0x34, 0x02, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x4C, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x05, 0x20, 0x06, 0x08]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x64, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::short_addressed_header(0x12, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x41, 0x88, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// # ]);
+    /// # let delay = NoopDelay::new();
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi)).with_event_wait(Polling::new(delay, 100));
+    /// let mut buffer = [0; 127];
+    /// 
+    /// // Give up if no frame has been received within 40 µs.
+    /// let timeouts = RxTimeouts { frame: Some(Duration::from_micros(40)), ..RxTimeouts::default() };
+    /// 
+    /// let frame = block_on(dw3xxx.receive(&mut buffer, timeouts))?;
+    /// assert_eq!(frame.crc(), CrcStatus::Ok);
+    /// assert_eq!(frame.payload(), &[0x41, 0x88, 0x00]);
+    /// assert_eq!(frame.prf(), Ok(PulseRepetitionFrequency::Prf64M));
+    /// assert_eq!(frame.rx_stamp(), 0x2000);
+    /// # dw3xxx.decompose().0.release().done();
+    /// # Ok::<(), dw3xxx::hl::ReceiveCommandError<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    /// 
+    pub async fn receive<'a>(&mut self, buffer: &'a mut [u8], timeouts: RxTimeouts) -> Result<ReceiverFrame<'a>, ReceiveCommandError<BUS::Error>> {
+        let crc = self.run_reception(Command::Rx, timeouts, false).await??;
 
-        todo!()
+        Ok(self.read_frame(buffer, crc).await.map_err(FastCommandError::from)?)
     }
 
-    /// Immediately transmits.
-    pub async fn transmit(&mut self) -> Result<(), FastCommandError> {
-        // Coverage for TX
+    /// 
+    /// Immediately transmits a frame, returning the time at which it was transmitted.
+    /// 
+    /// The frame is written with [`write_tx_frame`](DW3XXX::write_tx_frame), after which the transmitter events are cleared, and
+    /// only the `TXFRS` event is enabled in `SYS_ENABLE` and awaited with the [`EventWait`] until the frame has been sent. The
+    /// interrupts that were enabled before are then restored. The returned timestamp is read from the `TX_STAMP` field of
+    /// `TX_TIME`, in units of approximately 15.65 ps.
+    /// 
+    /// As the transmitter events are cleared before the transmission starts, a `TXFRS` event left pending by an earlier
+    /// transmission, for example one whose wait failed, is not mistaken for the completion of this one:
+    /// 
+    /// ```rust
+    /// # use embedded_hal_mock::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+    /// # use dw3xxx::hl::{block_on, Blocking, Polling, TxOptions, DW3XXX};
+    /// # use dw3xxx::ll::{commands::Command, spi::{self, AccessMode}};
+    /// # let spi = Mock::new(&[
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x10, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::short_addressed_header(0x14, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x41, 0x88, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x0C, 0x1C, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x05, 0x14, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     // The stale TXFRS is cleared before the transmission is started.
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x80, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::fast_command_header(Command::Tx).to_vec()),
+    /// #     Transaction::transaction_end(),
+    /// #     // The first poll no longer sees the stale TXFRS, so the driver keeps waiting.
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+    /// #     Transaction::write_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// #     Transaction::transaction_start(),
+    /// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x74, AccessMode::Read).to_vec()),
+    /// #     Transaction::read_vec(vec![0x00, 0x20, 0x00, 0x00, 0x00]),
+    /// #     Transaction::transaction_end(),
+    /// # ]);
+    /// # let delay = NoopDelay::new();
+    /// let mut dw3xxx = DW3XXX::new(Blocking::new(spi)).with_event_wait(Polling::new(delay, 100));
+    /// 
+    /// // TXFRS is still pending from an earlier transmission, yet only the TXFRS of this transmission completes it.
+    /// let tx_stamp = block_on(dw3xxx.transmit(&[0x41, 0x88, 0x00], TxOptions::default()))?;
+    /// assert_eq!(tx_stamp, 0x2000);
+    /// # dw3xxx.decompose().0.release().done();
+    /// # Ok::<(), dw3xxx::hl::TransmitCommandError<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    /// 
+    pub async fn transmit(&mut self, frame: &[u8], options: TxOptions) -> Result<u64, TransmitCommandError<BUS::Error>> {
+        self.write_tx_frame(frame, options).await?;
+        self.run_transmission(Command::Tx).await?;
 
-        todo!()
+        Ok(self.read::<TX_TIME>().await?.tx_stamp())
     }
 
-    /// Transmits and then receives.
-    pub async fn transmit_receive(&mut self) -> Result<ReceiverFrame, TransmitReceiveCommandError> {
-        // Coverage for TX_W4R
+    /// 
+    /// Immediately transmits a frame and then receives.
+    /// 
+    /// The frame is written with [`write_tx_frame`](DW3XXX::write_tx_frame), as with [`transmit`](DW3XXX::transmit). See
+    /// [`receive`](DW3XXX::receive).
+    /// 
+    pub async fn transmit_receive<'a>(&mut self, frame: &[u8], options: TxOptions, buffer: &'a mut [u8], timeouts: RxTimeouts) -> Result<ReceiverFrame<'a>, TransmitReceiveCommandError<BUS::Error>> {
+        self.write_tx_frame(frame, options).await?;
 
-        todo!()
+        let crc = self.run_reception(Command::TxW4r, timeouts, true).await??;
+
+        Ok(self.read_frame(buffer, crc).await.map_err(FastCommandError::from)?)
     }
 
     /// Listens for a preamble, and if one is not found, transmits.
-    pub async fn listen_transmit(&mut self) -> Result<(), FastCommandError> {
+    pub async fn listen_transmit(&mut self) -> Result<(), FastCommandError<BUS::Error>> {
         // Coverage for CCA_TX
 
         todo!()
     }
 
     /// Listens for a preamble, and if one is not found, transmits then receives.
-    pub async fn listen_transmit_receive(&mut self) -> Result<ReceiverFrame, TransmitReceiveCommandError> {
+    pub async fn listen_transmit_receive(&mut self) -> Result<ReceiverFrame<'_>, TransmitReceiveCommandError<BUS::Error>> {
         // Coverage for CCA_TX_W4R
 
         todo!()
     }
 
+    /// 
     /// Receives after a delay.
-    pub async fn delayed_receive(&mut self, kind: TransceiverDelay) -> Result<ReceiverFrame, ReceiveCommandError> {
-        // Coverage for:
-        //  - DRX
-        //  - DRX_TS
-        //  - DRX_RS
-        //  - DRX_REF
+    /// 
+    /// See [`receive`](DW3XXX::receive).
+    /// 
+    pub async fn delayed_receive<'a>(&mut self, kind: TransceiverDelay, buffer: &'a mut [u8], timeouts: RxTimeouts) -> Result<ReceiverFrame<'a>, ReceiveCommandError<BUS::Error>> {
+        let command = match kind {
+            TransceiverDelay::Absolute => Command::Drx,
+            TransceiverDelay::LastRx   => Command::DrxRs,
+            TransceiverDelay::LastTx   => Command::DrxTs,
+            TransceiverDelay::Internal => Command::DrxRef,
+        };
 
-        todo!()
+        let crc = self.run_reception(command, timeouts, false).await??;
+
+        Ok(self.read_frame(buffer, crc).await.map_err(FastCommandError::from)?)
     }
 
-    /// Transmits after a delay.
-    pub async fn delayed_transmit(&mut self, kind: TransceiverDelay) -> Result<(), FastCommandError> {
-        // Coverage for:
-        //  - DTX
-        //  - DTX_TS
-        //  - DTX_RS
-        //  - DTX_REF
+    /// Transmits the frame written with [`write_tx_frame`](DW3XXX::write_tx_frame) after a delay.
+    pub async fn delayed_transmit(&mut self, kind: TransceiverDelay) -> Result<(), FastCommandError<BUS::Error>> {
+        let command = match kind {
+            TransceiverDelay::Absolute => Command::Dtx,
+            TransceiverDelay::LastRx   => Command::DtxRs,
+            TransceiverDelay::LastTx   => Command::DtxTs,
+            TransceiverDelay::Internal => Command::DtxRef,
+        };
 
-        todo!()
+        self.run_transmission(command).await
     }
 
-    /// Transmits after a delay and then receives.
-    pub async fn delayed_transmit_receive(&mut self, kind: TransceiverDelay) -> Result<ReceiverFrame, TransmitReceiveCommandError> {
-        // Coverage for:
-        //  - DTX_W4R
-        //  - DTX_TS_W4R
-        //  - DTX_RS_W4R
-        //  - DTX_REF_W4R
+    /// 
+    /// Transmits the frame written with [`write_tx_frame`](DW3XXX::write_tx_frame) after a delay and then receives.
+    /// 
+    /// See [`receive`](DW3XXX::receive).
+    /// 
+    pub async fn delayed_transmit_receive<'a>(&mut self, kind: TransceiverDelay, buffer: &'a mut [u8], timeouts: RxTimeouts) -> Result<ReceiverFrame<'a>, TransmitReceiveCommandError<BUS::Error>> {
+        let command = match kind {
+            TransceiverDelay::Absolute => Command::DtxW4r,
+            TransceiverDelay::LastRx   => Command::DtxRsW4r,
+            TransceiverDelay::LastTx   => Command::DtxTsW4r,
+            TransceiverDelay::Internal => Command::DtxRefW4r,
+        };
 
-        todo!()
+        let crc = self.run_reception(command, timeouts, true).await??;
+
+        Ok(self.read_frame(buffer, crc).await.map_err(FastCommandError::from)?)
+    }
+
+    /// 
+    /// Executes a command that transmits, and waits until the frame has been transmitted.
+    /// 
+    /// The transmitter events are cleared first, and only the `TXFRS` event is enabled while waiting, after which the interrupts
+    /// that were enabled before are restored.
+    /// 
+    async fn run_transmission(&mut self, command: Command) -> Result<(), FastCommandError<BUS::Error>> {
+        let enabled = self.enabled_interrupts().await?;
+
+        // Events left pending by an earlier operation that did not finish would otherwise be mistaken for those of this one.
+        self.clear_interrupt_set(InterruptSet::TX_EVENTS).await?;
+        self.enable_interrupts(Interrupt::Txfrs.into()).await?;
+
+        let result = async {
+            self.execute(command).await?;
+            self.wait_for_transmission().await
+        }.await;
+
+        self.enable_interrupts(enabled).await?;
+
+        result
+    }
+
+    /// 
+    /// Executes a command that receives, optionally after transmitting, and waits until the receiver has finished.
+    /// 
+    /// The receiver events, and the transmitter events when transmitting, are cleared first, and only the events that finish the
+    /// transmission and reception are enabled while waiting, after which the interrupts that were enabled before are restored.
+    /// 
+    async fn run_reception(
+        &mut self,
+        command: Command,
+        timeouts: RxTimeouts,
+        transmits: bool
+    ) -> Result<Result<CrcStatus, ReceiverError>, FastCommandError<BUS::Error>> {
+        let events = match transmits {
+            true  => RX_COMPLETE | Interrupt::Txfrs,
+            false => RX_COMPLETE,
+        };
+
+        let stale = match transmits {
+            true  => RX_EVENTS | InterruptSet::TX_EVENTS,
+            false => RX_EVENTS,
+        };
+
+        self.set_rx_timeouts(timeouts).await?;
+        let enabled = self.enabled_interrupts().await?;
+
+        // Events left pending by an earlier operation that did not finish would otherwise be mistaken for those of this one.
+        self.clear_interrupt_set(stale).await?;
+        self.enable_interrupts(events).await?;
+
+        let result = async {
+            self.execute(command).await?;
+
+            if transmits {
+                self.wait_for_transmission().await?;
+            }
+
+            self.wait_for_frame().await
+        }.await;
+
+        self.enable_interrupts(enabled).await?;
+
+        result
+    }
+
+    /// Waits until any of the events are pending, returning the pending events.
+    async fn wait_for(&mut self, events: InterruptSet) -> Result<InterruptSet, FastCommandError<BUS::Error>> {
+        loop {
+            self.wait.wait().await.map_err(FastCommandError::IrqError)?;
+
+            let pending = self.interrupt_status().await?.intersection(events);
+
+            if !pending.is_empty() {
+                return Ok(pending);
+            }
+        }
+    }
+
+    /// Waits until the frame has been transmitted, then clears the transmitter events.
+    async fn wait_for_transmission(&mut self) -> Result<(), FastCommandError<BUS::Error>> {
+        self.wait_for(Interrupt::Txfrs.into()).await?;
+        self.clear_interrupt_set(InterruptSet::TX_EVENTS).await?;
+
+        Ok(())
+    }
+
+    /// Waits until the receiver has finished, then clears the receiver events and maps them to the result of the reception.
+    async fn wait_for_frame(&mut self) -> Result<Result<CrcStatus, ReceiverError>, FastCommandError<BUS::Error>> {
+        let events = self.wait_for(RX_COMPLETE).await?;
+        self.clear_interrupt_set(RX_EVENTS).await?;
+
+        Ok(ReceiverError::from_events(events))
+    }
+
+    /// Reads the information, timestamp, and payload of a received frame.
+    async fn read_frame<'a>(&mut self, buffer: &'a mut [u8], crc: CrcStatus) -> Result<ReceiverFrame<'a>, SpiError<BUS::Error>> {
+        let info = self.read::<RX_FINFO>().await?;
+        let rx_stamp = self.read::<RX_TIME>().await?.rx_stamp();
+
+        let len = (info.rxflen() as usize).saturating_sub(FCS_LEN).min(buffer.len());
+        let payload = &mut buffer[..len];
+
+        if !payload.is_empty() {
+            self.bus.short_addressed(RX_BUFFER_0::BASE_ADDRESS, AccessMode::Read, payload).await?;
+        }
+
+        Ok(ReceiverFrame { payload, info, rx_stamp, crc })
+    }
+}
+
+/// The receiver events, which are cleared once the receiver has finished.
+const RX_EVENTS: InterruptSet = InterruptSet::RX_GOOD.union(InterruptSet::RX_ERRORS).union(InterruptSet::RX_TIMEOUTS);
+
+/// The receiver events that indicate the receiver has finished, which are enabled while receiving.
+const RX_COMPLETE: InterruptSet = InterruptSet::from_slice(&[
+    Interrupt::Rxfcg, Interrupt::Rxfce, Interrupt::Rxpto, Interrupt::Rxprej, Interrupt::Rxsto, Interrupt::Rxfto, Interrupt::Rxphe,
+    Interrupt::Rxfsl, Interrupt::Ciaerr, Interrupt::Rxovrr, Interrupt::Arfe, Interrupt::Cperr
+]);
+
+/// 
+/// The interrupts serviced by [`service_interrupts`](DW3XXX::service_interrupts).
+/// 
+/// Iterating over the serviced interrupts yields one [`InterruptEvent`] per `FINT_STAT` category that was set, in order of the
+/// category bits.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterruptEvents {
+    categories: u8,
+    events: InterruptSet
+}
+
+impl InterruptEvents {
+    /// Whether no interrupts were pending.
+    pub fn is_empty(&self) -> bool {
+        self.categories == 0
+    }
+
+    /// Every `SYS_STATUS` event that was serviced and cleared.
+    pub fn events(&self) -> InterruptSet {
+        self.events
+    }
+
+    /// Iterates over the serviced interrupts.
+    pub fn iter(&self) -> InterruptEventsIter {
+        InterruptEventsIter { serviced: *self, index: 0 }
+    }
+}
+
+impl IntoIterator for InterruptEvents {
+    type Item = InterruptEvent;
+    type IntoIter = InterruptEventsIter;
+
+    fn into_iter(self) -> InterruptEventsIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the [`InterruptEvent`]s of an [`InterruptEvents`].
+#[derive(Debug, Clone)]
+pub struct InterruptEventsIter {
+    serviced: InterruptEvents,
+    index: usize
+}
+
+impl Iterator for InterruptEventsIter {
+    type Item = InterruptEvent;
+
+    fn next(&mut self) -> Option<InterruptEvent> {
+        while let Some(&category) = FastInterrupt::ALL.get(self.index) {
+            self.index += 1;
+
+            if self.serviced.categories & category.mask() != 0 {
+                return Some(InterruptEvent::new(category, self.serviced.events.intersection(category.events())));
+            }
+        }
+
+        None
+    }
+}
+
+/// 
+/// A category of interrupt serviced by [`service_interrupts`](DW3XXX::service_interrupts), along with the `SYS_STATUS` events of
+/// the category that were cleared.
+/// 
+/// The set of events may be empty if the events were already cleared by the time `SYS_STATUS` was read.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptEvent {
+    /// A frame has been transmitted.
+    TxOk(InterruptSet),
+    /// A transmission was cancelled because a preamble was detected.
+    CcaFail(InterruptSet),
+    /// The CIA could not compute the receive timestamp.
+    RxTimestampError(InterruptSet),
+    /// A frame has been received.
+    RxOk(InterruptSet),
+    /// An error occurred while receiving a frame.
+    RxError(InterruptSet),
+    /// The receiver timed out.
+    RxTimeout(InterruptSet),
+    /// A system event occurred.
+    SysEvent(InterruptSet),
+    /// A system error occurred.
+    SysPanic(InterruptSet)
+}
+
+impl InterruptEvent {
+    fn new(category: FastInterrupt, events: InterruptSet) -> Self {
+        match category {
+            FastInterrupt::TxOk     => InterruptEvent::TxOk(events),
+            FastInterrupt::CcaFail  => InterruptEvent::CcaFail(events),
+            FastInterrupt::RxTsErr  => InterruptEvent::RxTimestampError(events),
+            FastInterrupt::RxOk     => InterruptEvent::RxOk(events),
+            FastInterrupt::RxErr    => InterruptEvent::RxError(events),
+            FastInterrupt::RxTo     => InterruptEvent::RxTimeout(events),
+            FastInterrupt::SysEvent => InterruptEvent::SysEvent(events),
+            FastInterrupt::SysPanic => InterruptEvent::SysPanic(events),
+        }
     }
 }
 
+/// The length of the FCS appended to frames by the device.
+const FCS_LEN: usize = 2;
+/// The maximum length of a frame, including the FCS, with the standard PHR.
+const MAX_STANDARD_FRAME_LEN: usize = 127;
+/// The maximum length of a frame, including the FCS, with the extended PHR.
+const MAX_EXTENDED_FRAME_LEN: usize = 1023;
+/// The number of octets written to `TX_BUFFER` in each transaction.
+const TX_CHUNK_LEN: usize = 128;
+
+/// 
+/// The options of a frame written with [`write_tx_frame`](DW3XXX::write_tx_frame), [`transmit`](DW3XXX::transmit) or
+/// [`transmit_receive`](DW3XXX::transmit_receive).
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TxOptions {
+    /// Sets the ranging bit in the PHR, marking the frame as a ranging frame.
+    pub ranging: bool,
+    /// The offset into `TX_BUFFER` at which the frame is written, and from which it is transmitted.
+    pub buffer_offset: u16
+}
+
 /// The baseline from which a delayed transceiver operation is measured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransceiverDelay {
     /// Calculates the delay as an absolute value.
     Absolute, // Regular variant
@@ -121,7 +988,10 @@ pub enum TransceiverDelay {
 
 /// An error resulting from the [`transmit_receive`](DW3XXX::transmit_receive), [`listen_transmit_receive`](DW3XXX::listen_transmit_receive),
 /// and [`delayed_transmit_receive`](DW3XXX::delayed_transmit_receive) methods.
-pub enum TransmitReceiveCommandError {
+#[derive(Debug)]
+pub enum TransmitReceiveCommandError<E> {
+    /// The frame is too long for the PHR mode, or the frame or its buffer offset does not fit within `TX_BUFFER`.
+    FrameLength,
     /// One of the receiver related errors.
     /// 
     /// See [`ReceiverError`].
@@ -129,11 +999,23 @@ pub enum TransmitReceiveCommandError {
     /// One of the fast command related errors.
     /// 
     /// See [`FastCommandError`].
-    CommandError(FastCommandError)
+    CommandError(FastCommandError<E>)
+}
+
+/// An error resulting from the [`transmit`](DW3XXX::transmit) and [`write_tx_frame`](DW3XXX::write_tx_frame) methods.
+#[derive(Debug)]
+pub enum TransmitCommandError<E> {
+    /// The frame is too long for the PHR mode, or the frame or its buffer offset does not fit within `TX_BUFFER`.
+    FrameLength,
+    /// One of the fast command related errors.
+    /// 
+    /// See [`FastCommandError`].
+    CommandError(FastCommandError<E>)
 }
 
 /// An error resulting from the [`receive`](DW3XXX::receive) and [`delayed_receive`](DW3XXX::delayed_receive) methods.
-pub enum ReceiveCommandError {
+#[derive(Debug)]
+pub enum ReceiveCommandError<E> {
     /// One of the receiver related errors.
     /// 
     /// See [`ReceiverError`].
@@ -141,10 +1023,11 @@ pub enum ReceiveCommandError {
     /// One of the fast command related errors.
     /// 
     /// See [`FastCommandError`].
-    CommandError(FastCommandError)
+    CommandError(FastCommandError<E>)
 }
 
 /// An error resulting from receiver operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReceiverError {
     PreambleTimeout,        // Coverage for RXPTO
     PreambleRejection,      // Coverage for RXPREJ
@@ -156,39 +1039,204 @@ pub enum ReceiverError {
     /// Receiver double buffer overrun.
     /// 
     /// This error will only ever occur when the optional double buffering functionality is enabled.
-    DoubleBufferOverrun     // Coverage for RXOVRR
+    DoubleBufferOverrun,    // Coverage for RXOVRR
+    /// The frame was rejected by the automatic frame filtering.
+    /// 
+    /// This error will only ever occur when frame filtering is enabled with the `FFEN` bit of `SYS_CFG`.
+    FrameFiltered,          // Coverage for ARFE
+    /// The STS quality check failed, such that the timestamp of the frame should not be trusted.
+    StsError,               // Coverage for CPERR
+    /// The receiver finished with events that are not mapped to any other error.
+    UnknownEvents(InterruptSet)
+}
+
+impl ReceiverError {
+    /// Maps the events of a finished reception to a received frame or the first receiver error.
+    fn from_events(events: InterruptSet) -> Result<CrcStatus, ReceiverError> {
+        const ERRORS: [(Interrupt, ReceiverError); 10] = [
+            (Interrupt::Rxpto,  ReceiverError::PreambleTimeout),
+            (Interrupt::Rxprej, ReceiverError::PreambleRejection),
+            (Interrupt::Rxsto,  ReceiverError::SfdTimeout),
+            (Interrupt::Rxfto,  ReceiverError::FrameTimeout),
+            (Interrupt::Rxphe,  ReceiverError::PhrDecodeError),
+            (Interrupt::Rxfsl,  ReceiverError::ReedSolomonDecodeError),
+            (Interrupt::Ciaerr, ReceiverError::CiaTimeout),
+            (Interrupt::Rxovrr, ReceiverError::DoubleBufferOverrun),
+            (Interrupt::Arfe,   ReceiverError::FrameFiltered),
+            (Interrupt::Cperr,  ReceiverError::StsError),
+        ];
+
+        if events.contains(Interrupt::Rxfcg) {
+            return Ok(CrcStatus::Ok);
+        }
+
+        if events.contains(Interrupt::Rxfce) {
+            return Ok(CrcStatus::Error);
+        }
+
+        match ERRORS.into_iter().find(|(interrupt, _)| events.contains(*interrupt)) {
+            Some((_, error)) => Err(error),
+            None             => Err(ReceiverError::UnknownEvents(events)),
+        }
+    }
+}
+
+/// 
+/// A frame resulting from receiver operations.
+/// 
+/// The payload borrows the buffer given to the receiving method, while the remaining information is read from the `RX_FINFO` and
+/// `RX_TIME` registers.
+/// 
+#[derive(Debug)]
+pub struct ReceiverFrame<'a> {
+    payload: &'a [u8],
+    info: RxFinfoValue,
+    rx_stamp: u64,
+    crc: CrcStatus
+}
+
+impl<'a> ReceiverFrame<'a> {
+    /// Returns the payload of the frame, excluding the FCS.
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Returns the length of the frame reported by the `RXFLEN` field of `RX_FINFO`, including the FCS.
+    pub fn frame_len(&self) -> usize {
+        self.info.rxflen() as usize
+    }
+
+    /// Returns whether the payload was truncated because the buffer was too short.
+    pub fn is_truncated(&self) -> bool {
+        self.payload.len() < self.frame_len().saturating_sub(FCS_LEN)
+    }
+
+    /// Returns whether the frame passed the CRC check.
+    pub fn crc(&self) -> CrcStatus {
+        self.crc
+    }
+
+    /// Returns the data rate of the frame, as reported by the `RXBR` field of `RX_FINFO`.
+    pub fn data_rate(&self) -> Result<DataRate, ReservedValue<u8>> {
+        self.info.rxbr()
+    }
+
+    /// Returns the PRF of the frame, as reported by the `RXPRF` field of `RX_FINFO`.
+    pub fn prf(&self) -> Result<PulseRepetitionFrequency, ReservedValue<u8>> {
+        self.info.rxprf()
+    }
+
+    /// Returns the approximate length of the preamble, as reported by the `RXPSR` field of `RX_FINFO`.
+    pub fn preamble_length(&self) -> Result<RxPreambleLength, ReservedValue<u8>> {
+        self.info.rxpsr()
+    }
+
+    /// Returns the number of preamble symbols accumulated, as reported by the `RXPACC` field of `RX_FINFO`.
+    pub fn preamble_accumulation_count(&self) -> u16 {
+        self.info.rxpacc()
+    }
+
+    /// Returns whether the ranging bit was set in the PHR of the frame.
+    pub fn ranging(&self) -> bool {
+        self.info.rng()
+    }
+
+    /// Returns the time at which the frame was received, read from the `RX_STAMP` field of `RX_TIME`, in units of approximately 15.65 ps.
+    pub fn rx_stamp(&self) -> u64 {
+        self.rx_stamp
+    }
 }
 
-/// A data frame resulting from receiver operations
-pub enum ReceiverFrame {
-    /// A received frame that has passed the CRC check.
-    Ok(),     // Coverage for RXFCG
-    /// A received frame that has not passed the CRC check.
-    Partial() // Coverage for RXFCE
+/// The result of the CRC check of a received frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrcStatus {
+    /// The frame passed the CRC check.
+    Ok,   // Coverage for RXFCG
+    /// The frame did not pass the CRC check.
+    Error // Coverage for RXFCE
 }
 
-/// An error resulting from fast command SPI transactions.
-pub enum FastCommandError {
+/// An error resulting from executing a fast command.
+#[derive(Debug)]
+pub enum FastCommandError<E> {
     /// One of the SPI related errors.
     /// 
     /// See [`SpiError`].
-    SpiError(SpiError),
-    /// An error executing a fast command.
+    SpiError(SpiError<E>),
+    /// A delayed fast command was rejected by the device.
     /// 
-    /// Usually from attempting to execute fast commands in too quick of succession.
-    FastCommandError // Coverage for CMD_ERR
+    /// Raised when the start time of a delayed transmission or reception had already passed, see [`DW3XXX::execute`].
+    FastCommandError, // Coverage for CMD_ERR
+    /// An error from the IRQ pin while waiting for the device to complete the command.
+    IrqError(embedded_hal::digital::ErrorKind)
+}
+
+impl<E> From<FastCommandError<E>> for TransmitCommandError<E> {
+    fn from(error: FastCommandError<E>) -> Self {
+        TransmitCommandError::CommandError(error)
+    }
+}
+
+impl<E> From<SpiError<E>> for TransmitCommandError<E> {
+    fn from(error: SpiError<E>) -> Self {
+        TransmitCommandError::CommandError(FastCommandError::SpiError(error))
+    }
+}
+
+impl<E> From<FastCommandError<E>> for ReceiveCommandError<E> {
+    fn from(error: FastCommandError<E>) -> Self {
+        ReceiveCommandError::CommandError(error)
+    }
+}
+
+impl<E> From<ReceiverError> for ReceiveCommandError<E> {
+    fn from(error: ReceiverError) -> Self {
+        ReceiveCommandError::ReceiverError(error)
+    }
+}
+
+impl<E> From<FastCommandError<E>> for TransmitReceiveCommandError<E> {
+    fn from(error: FastCommandError<E>) -> Self {
+        TransmitReceiveCommandError::CommandError(error)
+    }
+}
+
+impl<E> From<TransmitCommandError<E>> for TransmitReceiveCommandError<E> {
+    fn from(error: TransmitCommandError<E>) -> Self {
+        match error {
+            TransmitCommandError::FrameLength         => TransmitReceiveCommandError::FrameLength,
+            TransmitCommandError::CommandError(error) => TransmitReceiveCommandError::CommandError(error),
+        }
+    }
+}
+
+impl<E> From<ReceiverError> for TransmitReceiveCommandError<E> {
+    fn from(error: ReceiverError) -> Self {
+        TransmitReceiveCommandError::ReceiverError(error)
+    }
+}
+
+impl<E> From<SpiError<E>> for FastCommandError<E> {
+    fn from(error: SpiError<E>) -> Self {
+        FastCommandError::SpiError(error)
+    }
 }
 
 /// An error resulting from SPI transactions.
-pub enum SpiError {
+#[derive(Debug)]
+pub enum SpiError<E> {
+    /// An error from the underlying SPI bus.
+    BusError(E),
     /// SPI transaction failed the CRC check.
     /// 
-    /// This error will only ever occur when the optional SPI CRC functionality is enabled.
+    /// This error will only ever occur when the optional SPI CRC functionality is enabled, see [`DW3XXX::set_crc_mode`].
     CrcError,       // Coverage for SPICRCE
     /// SPI Overflow.
     OverflowError,  // Coverage for SPI_OVF
     /// SPI Underflow.
     UnderflowError, // Coverage for SPI_UNF
     /// SPI collision from internal contention with the device.
-    CollisionError  // Coverage for SPIERR
+    CollisionError, // Coverage for SPIERR
+    /// An access that extends beyond the end of the register.
+    OutOfBounds
 }
\ No newline at end of file
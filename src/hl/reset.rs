@@ -0,0 +1,196 @@
+//! 
+//! The soft reset protocol and the resets of individual device blocks.
+//! 
+//! See [`DW3XXX::soft_reset`], [`DW3XXX::hard_reset`] and [`DW3XXX::reset_block`].
+//! 
+
+use embedded_hal::digital::{Error, ErrorKind, OutputPin};
+
+use crate::ll::reg::{
+    AonCfgValue, AonCtrlValue, AonDigCfgValue, Register, SoftRstValue, SysClk, AON_CFG, AON_CTRL, AON_DIG_CFG, CLK_CTRL, SOFT_RST
+};
+use crate::ll::spi::CrcMode;
+
+use super::{Bus, BusDelay, DeviceId, InitError, InitStep, SpiError, DW3XXX};
+
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// 
+    /// Resets a single block of the device, such as the receiver when it is stuck, without resetting the entire device.
+    /// 
+    /// The reset bits of `SOFT_RST` are active low, so the bit of the block is cleared and then set again.
+    /// 
+    pub async fn reset_block(&mut self, block: ResetBlock) -> Result<(), SpiError<BUS::Error>> {
+        let mut reset = SoftRstValue::default();
+
+        match block {
+            ResetBlock::Rx   => reset.set_rx_rst(false),
+            ResetBlock::Tx   => reset.set_tx_rst(false),
+            ResetBlock::Cia  => reset.set_cia_rst(false),
+            ResetBlock::Gpio => reset.set_gpio_rst(false),
+        };
+
+        self.write::<SOFT_RST>(reset).await?;
+        self.write::<SOFT_RST>(SoftRstValue::default()).await
+    }
+
+    /// 
+    /// Performs a soft reset of the entire device, and then initialises it again.
+    /// 
+    /// This follows the soft reset sequence of the manufacturer's driver:
+    /// 
+    ///  1. The AON configuration is cleared, such that the reset does not download a stale configuration from the AON array, and the
+    ///     cleared configuration is saved to the AON array. The driver then waits 1 ms for the save to complete.
+    ///  2. The system clock is forced to the FOSC clock, as the PLL is switched off by the reset.
+    ///  3. Every block is reset by clearing `SOFT_RST`. The device releases the reset itself, so the release is not written.
+    ///  4. The driver waits 1 ms before any further SPI transaction, while the device returns to the `IDLE_RC` state with all
+    ///     registers at their reset values.
+    /// 
+    /// The device is then initialised again with [`init`](DW3XXX::init), and the configuration last applied with
+    /// [`apply_config`](DW3XXX::apply_config) is applied again. Finally the SPI CRC mode is restored, as both are lost by the reset.
+    /// 
+    /// As the AON configuration is cleared, any sleep or wake-up configuration must also be written again after the reset.
+    /// 
+    pub async fn soft_reset(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<DeviceId, InitError<BUS::Error>> {
+        let crc = self.bus.crc_mode();
+
+        self.clear_aon_config().await.map_err(InitError::spi(InitStep::SoftReset))?;
+        delay.delay_us(SOFT_RESET_WAIT_US).await;
+
+        self.modify::<CLK_CTRL>(|r| r.set_sys_clk(SysClk::Fosc)).await.map_err(InitError::spi(InitStep::SoftReset))?;
+
+        // Clearing every bit resets every block, after which the device releases the reset itself.
+        self.write::<SOFT_RST>(SoftRstValue::from(SOFT_RST::ZEROED)).await.map_err(InitError::spi(InitStep::SoftReset))?;
+        self.bus.set_crc_mode(CrcMode::Disabled);
+        delay.delay_us(SOFT_RESET_WAIT_US).await;
+
+        let device = self.init(delay).await?;
+
+        self.reapply_config(delay).await?;
+        self.set_crc_mode(crc).await.map_err(InitError::spi(InitStep::SoftReset))?;
+
+        Ok(device)
+    }
+
+    /// Clears the wake-up and sleep configuration, and saves the cleared configuration to the AON array.
+    async fn clear_aon_config(&mut self) -> Result<(), SpiError<BUS::Error>> {
+        self.write::<AON_DIG_CFG>(AonDigCfgValue::from(AON_DIG_CFG::ZEROED)).await?;
+        self.write::<AON_CFG>(AonCfgValue::from(AON_CFG::ZEROED)).await?;
+
+        self.write::<AON_CTRL>(AonCtrlValue::from(AON_CTRL::ZEROED)).await?;
+        self.write::<AON_CTRL>(*AonCtrlValue::from(AON_CTRL::ZEROED).set_save(true)).await
+    }
+
+    /// Applies the configuration kept by the driver again after a reset, if there is one.
+    async fn reapply_config(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<(), InitError<BUS::Error>> {
+        match self.config {
+            Some(config) => self.apply_config(config, delay).await.map_err(InitError::ConfigError),
+            None         => Ok(()),
+        }
+    }
+}
+
+impl<BUS: Bus, WAIT, RESET: ResetPin> DW3XXX<BUS, WAIT, RESET> {
+    /// 
+    /// Performs a hard reset of the device with the `RSTn` line, and then initialises it again.
+    /// 
+    /// This recovers a device that no longer responds over SPI. The `RSTn` line is held low for 10 µs and then released by the
+    /// [`ResetPin`], leaving it floating such that the device pulls it high itself. The `RSTn` line is never driven high. After
+    /// which the device is given 2 ms to start its oscillators before waiting for it to enter the `IDLE_RC` state. The device is
+    /// then initialised with [`init`](DW3XXX::init), which verifies the device through `DEV_ID`, and the configuration last applied
+    /// with [`apply_config`](DW3XXX::apply_config) is applied again.
+    /// 
+    /// Unlike [`soft_reset`](DW3XXX::soft_reset), the SPI CRC mode is left disabled, as the device may not have been in the expected
+    /// mode before the reset.
+    /// 
+    pub async fn hard_reset(&mut self, delay: &mut impl BusDelay<BUS>) -> Result<DeviceId, InitError<BUS::Error>> {
+        self.reset.assert_reset().map_err(InitError::ResetPinError)?;
+        delay.delay_us(RESET_PULSE_US).await;
+        self.reset.release_reset().map_err(InitError::ResetPinError)?;
+
+        self.bus.set_crc_mode(CrcMode::Disabled);
+        delay.delay_us(RESET_WAKE_UP_US).await;
+
+        let device = self.init(delay).await?;
+
+        self.reapply_config(delay).await?;
+
+        Ok(device)
+    }
+}
+
+/// The time in microseconds for which the `RSTn` line is held low during a [`hard_reset`](DW3XXX::hard_reset).
+const RESET_PULSE_US: u32 = 10;
+/// The time in microseconds given to the device to wake up after the `RSTn` line is released, before it is polled.
+const RESET_WAKE_UP_US: u32 = 2000;
+/// The time in microseconds waited after the AON configuration is saved, and after a [`soft_reset`](DW3XXX::soft_reset).
+const SOFT_RESET_WAIT_US: u32 = 1000;
+
+/// 
+/// A means of driving the `RSTn` line of the device, used by [`hard_reset`](DW3XXX::hard_reset).
+/// 
+/// The `RSTn` line is also driven by the device itself, and must never be driven high by the host. An implementation must therefore
+/// only ever pull the line low, or leave it floating. See [`OpenDrainPin`] for an implementation using an open-drain output.
+/// 
+pub trait ResetPin {
+    /// Pulls the `RSTn` line low, holding the device in reset.
+    fn assert_reset(&mut self) -> Result<(), ErrorKind>;
+    /// Releases the `RSTn` line, leaving it floating such that the device pulls it high itself.
+    fn release_reset(&mut self) -> Result<(), ErrorKind>;
+}
+
+/// 
+/// A [`ResetPin`] using an [`OutputPin`] configured as an open-drain output.
+/// 
+/// The pin must be configured as open-drain, such that setting it high releases the `RSTn` line instead of driving it. A push-pull
+/// output would drive the line high against the device, which is not allowed.
+/// 
+/// ```rust
+/// # use embedded_hal_mock::eh1::digital::{Mock, State, Transaction};
+/// # use dw3xxx::hl::{OpenDrainPin, ResetPin};
+/// # let pin = Mock::new(&[Transaction::set(State::Low), Transaction::set(State::High)]);
+/// let mut reset = OpenDrainPin::new(pin);
+/// 
+/// reset.assert_reset().unwrap();
+/// reset.release_reset().unwrap();
+/// # reset.release().done();
+/// ```
+/// 
+pub struct OpenDrainPin<PIN> {
+    pin: PIN
+}
+
+impl<PIN: OutputPin> OpenDrainPin<PIN> {
+    /// Constructs a new instance of [`OpenDrainPin`] from a pin that is configured as an open-drain output.
+    pub fn new(pin: PIN) -> Self {
+        Self { pin }
+    }
+
+    /// Releases the underlying pin.
+    pub fn release(self) -> PIN {
+        self.pin
+    }
+}
+
+impl<PIN: OutputPin> ResetPin for OpenDrainPin<PIN> {
+    fn assert_reset(&mut self) -> Result<(), ErrorKind> {
+        self.pin.set_low().map_err(|error| error.kind())
+    }
+
+    fn release_reset(&mut self) -> Result<(), ErrorKind> {
+        // Setting an open-drain output high leaves the line floating.
+        self.pin.set_high().map_err(|error| error.kind())
+    }
+}
+
+/// A block of the device that may be reset individually with [`reset_block`](DW3XXX::reset_block).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetBlock {
+    /// The receiver.
+    Rx,
+    /// The transmitter.
+    Tx,
+    /// The channel impulse response analyser.
+    Cia,
+    /// The GPIO block.
+    Gpio
+}
@@ -0,0 +1,106 @@
+//! 
+//! The timeouts of the receiver.
+//! 
+//! See [`RxTimeouts`].
+//! 
+
+use core::time::Duration;
+
+use crate::ll::reg::{sys_cfg, PreTocValue, RxFwtoValue, PRE_TOC, RX_FWTO};
+
+use super::config::{pac_symbols, prf, Prf};
+use super::{Bus, SpiError, DW3XXX};
+
+/// The largest value of the `RX_FWTO` register.
+const MAX_FRAME_TIMEOUT: u32 = 0xFF_FFFF;
+/// The duration of a preamble symbol in picoseconds with a 16 MHz PRF.
+const SYMBOL_PS_PRF_16: u64 = 993_590;
+/// The duration of a preamble symbol in picoseconds with a 64 MHz PRF.
+const SYMBOL_PS_PRF_64: u64 = 1_017_630;
+
+/// 
+/// The timeouts of a reception, given to the receiving methods such as [`receive`](DW3XXX::receive).
+/// 
+/// A timeout of [`None`] disables it, so the [`Default`] waits until a frame is received. The timeouts are rounded up to the units
+/// of the device, and saturate at the largest timeout the device supports.
+/// 
+/// ```rust
+/// # use core::time::Duration;
+/// # use dw3xxx::hl::RxTimeouts;
+/// let timeouts = RxTimeouts { frame: Some(Duration::from_millis(5)), ..RxTimeouts::default() };
+/// assert_eq!(timeouts, RxTimeouts::from_micros(Some(5000), None));
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RxTimeouts {
+    /// 
+    /// The time from enabling the receiver until a frame must have been received, after which the receiver is disabled and
+    /// [`ReceiverError::FrameTimeout`](super::ReceiverError::FrameTimeout) is returned.
+    /// 
+    /// This is programmed into `RX_FWTO` in units of 512 / 499.2 MHz (≈ 1.026 µs), with a maximum of roughly 17.2 s.
+    /// 
+    pub frame: Option<Duration>,
+    /// 
+    /// The time from enabling the receiver until a preamble must have been detected, after which the receiver is disabled and
+    /// [`ReceiverError::PreambleTimeout`](super::ReceiverError::PreambleTimeout) is returned.
+    /// 
+    /// This is programmed into `PRE_TOC` in units of the PAC size of the configuration applied with
+    /// [`apply_config`](DW3XXX::apply_config). The timeout is rounded up to a whole number of PACs, of which one is subtracted as the
+    /// device adds a PAC to the programmed value, so the shortest timeout is two PACs.
+    /// 
+    pub preamble: Option<Duration>
+}
+
+impl RxTimeouts {
+    /// Constructs a new instance of [`RxTimeouts`] from timeouts in microseconds.
+    pub const fn from_micros(frame_us: Option<u64>, preamble_us: Option<u64>) -> Self {
+        let frame = match frame_us {
+            Some(us) => Some(Duration::from_micros(us)),
+            None     => None,
+        };
+
+        let preamble = match preamble_us {
+            Some(us) => Some(Duration::from_micros(us)),
+            None     => None,
+        };
+
+        RxTimeouts { frame, preamble }
+    }
+}
+
+impl<BUS: Bus, WAIT, RESET> DW3XXX<BUS, WAIT, RESET> {
+    /// Programs the frame wait and preamble detection timeouts before enabling the receiver.
+    pub(super) async fn set_rx_timeouts(&mut self, timeouts: RxTimeouts) -> Result<(), SpiError<BUS::Error>> {
+        match timeouts.frame {
+            Some(timeout) => {
+                // The units are 512 / 499.2 MHz, which is exactly 40 / 39 µs. A value of zero disables the timeout, so the shortest
+                // timeout is a single unit.
+                let units = (timeout.as_nanos() * 39).div_ceil(40_000).clamp(1, MAX_FRAME_TIMEOUT as u128) as u32;
+
+                self.write::<RX_FWTO>(*RxFwtoValue::default().set_value(units)).await?;
+                self.modify_field::<sys_cfg::RXWTOE>(true).await?;
+            },
+            None => self.modify_field::<sys_cfg::RXWTOE>(false).await?,
+        }
+
+        let config = self.config.unwrap_or_default();
+
+        let pac_units = match timeouts.preamble {
+            Some(timeout) => {
+                let symbol_ps = match prf(config.rx_preamble_code) {
+                    Some(Prf::Prf16) => SYMBOL_PS_PRF_16,
+                    _                => SYMBOL_PS_PRF_64,
+                };
+                let pac_ps = symbol_ps as u128 * pac_symbols(config.pac_size) as u128;
+
+                // The device adds a PAC to the programmed value, and a value of zero disables the timeout, so the value is at least
+                // one, giving a shortest timeout of two PACs.
+                let pacs = (timeout.as_nanos() * 1000).div_ceil(pac_ps);
+                pacs.saturating_sub(1).clamp(1, u16::MAX as u128) as u16
+            },
+            None => 0,
+        };
+
+        self.write::<PRE_TOC>(*PreTocValue::default().set_value(pac_units)).await
+    }
+}
@@ -0,0 +1,297 @@
+//! 
+//! Waiting for events raised by the device.
+//! 
+//! Operations that complete asynchronously on the device, such as transmission and reception, are awaited by the
+//! [`DW3XXX`](super::DW3XXX) driver with an [`EventWait`]. After each wait the driver checks the `SYS_STATUS` register, so an
+//! [`EventWait`] may return early (e.g. on a spurious wake) without affecting the result of the operation.
+//! 
+//!  - [`Polling`] delays for a fixed interval with an [`embedded_hal::delay::DelayNs`], for boards on which the IRQ line of the
+//!    device is not connected. The delay blocks, so this is intended for use with the [`Blocking`](super::Blocking) bus.
+//!  - `AsyncPolling` awaits a fixed interval with an `embedded_hal_async::delay::DelayNs`, for use with the `Async` bus on boards on
+//!    which the IRQ line of the device is not connected, and is only available with the `async` feature enabled.
+//!  - `IrqPin` awaits the IRQ line of the device with an `embedded_hal_async::digital::Wait`, and is only available with the
+//!    `async` feature enabled.
+//! 
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::ErrorKind;
+
+/// 
+/// A means of waiting for the device to raise an event.
+/// 
+/// The driver enables only the events of the current operation in the `SYS_ENABLE` register before waiting, so an implementation
+/// may wait for the IRQ line of the device to be asserted.
+/// 
+#[allow(async_fn_in_trait)]
+pub trait EventWait {
+    /// Waits until the device may have raised an event.
+    async fn wait(&mut self) -> Result<(), ErrorKind>;
+}
+
+/// 
+/// An [`EventWait`] that polls the device at a fixed interval.
+/// 
+/// Each wait delays for the polling interval, after which the driver checks the `SYS_STATUS` register, giving the same completion
+/// and error semantics as waiting on the IRQ line. A shorter interval reduces the latency of detecting events at the cost of more
+/// SPI transactions.
+/// 
+/// The delay is a blocking [`DelayNs`], which stalls the executor for the whole interval, so this is only intended for use with the
+/// [`Blocking`](super::Blocking) bus. With the `Async` bus use `AsyncPolling` instead.
+/// 
+/// ```rust
+/// # use embedded_hal_mock::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+/// # use dw3xxx::hl::{block_on, Blocking, Polling, TxOptions, DW3XXX};
+/// # use dw3xxx::ll::{commands::Command, spi::{self, AccessMode}};
+/// # let spi = Mock::new(&[
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x10, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::short_addressed_header(0x14, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x41, 0x88, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x0C, 0x1C, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x05, 0x14, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x02, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x80, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::fast_command_header(Command::Tx).to_vec()),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x02, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x74, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x00, 0x10, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// # ]);
+/// # let delay = NoopDelay::new();
+/// // Check for events every 100 µs.
+/// let mut dw3xxx = DW3XXX::new(Blocking::new(spi)).with_event_wait(Polling::new(delay, 100));
+/// 
+/// let tx_stamp = block_on(dw3xxx.transmit(&[0x41, 0x88, 0x00], TxOptions::default()))?;
+/// assert_eq!(tx_stamp, 0x1000);
+/// # dw3xxx.decompose().0.release().done();
+/// # Ok::<(), dw3xxx::hl::TransmitCommandError<embedded_hal::spi::ErrorKind>>(())
+/// ```
+/// 
+pub struct Polling<DELAY> {
+    delay: DELAY,
+    interval_us: u32
+}
+
+impl<DELAY> Polling<DELAY> {
+    /// Constructs a new instance of [`Polling`] with the polling interval in microseconds.
+    pub fn new(delay: DELAY, interval_us: u32) -> Self {
+        Self { delay, interval_us }
+    }
+
+    /// The polling interval in microseconds.
+    pub fn interval_us(&self) -> u32 {
+        self.interval_us
+    }
+
+    /// Sets the polling interval in microseconds.
+    pub fn set_interval_us(&mut self, interval_us: u32) {
+        self.interval_us = interval_us;
+    }
+
+    /// Releases the underlying delay.
+    pub fn release(self) -> DELAY {
+        self.delay
+    }
+}
+
+impl<DELAY: DelayNs> EventWait for Polling<DELAY> {
+    async fn wait(&mut self) -> Result<(), ErrorKind> {
+        self.delay.delay_us(self.interval_us);
+
+        Ok(())
+    }
+}
+
+/// 
+/// An [`EventWait`] that awaits the IRQ line of the device.
+/// 
+/// The IRQ line is active high, and remains asserted for as long as any enabled event is pending in `SYS_STATUS`. This allows the
+/// CPU to sleep while the device is transmitting or receiving, which is essential for low-power devices.
+/// 
+/// Only the events of the current operation are enabled while waiting, so an unrelated event that is still pending, such as
+/// `SPIRDY`, does not hold the IRQ line high and the wait does not return until the operation has finished:
+/// 
+/// ```rust
+/// # #[cfg(feature = "async")] {
+/// # use embedded_hal_mock::eh1::{digital, spi::{Mock, Transaction}};
+/// # use embassy_futures::block_on;
+/// # use dw3xxx::hl::{Async, IrqPin, TxOptions, DW3XXX};
+/// # use dw3xxx::ll::{commands::Command, spi::{self, AccessMode}};
+/// # let spi = Mock::new(&[
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x10, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::short_addressed_header(0x14, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x41, 0x88, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x0C, 0x1C, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x24, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x05, 0x14, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     // SPIRDY is enabled, and remains pending from the wake-up of the device.
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x00, 0x00, 0x80, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     // Only TXFRS is enabled while transmitting.
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x80, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::fast_command_header(Command::Tx).to_vec()),
+/// #     Transaction::transaction_end(),
+/// #     // The IRQ line is asserted once, by TXFRS, so SYS_STATUS is read only once.
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0xF0, 0x00, 0x80, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x44, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0xF0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     // SPIRDY is enabled again once the transmission has finished.
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x3C, AccessMode::Write).to_vec()),
+/// #     Transaction::write_vec(vec![0x00, 0x00, 0x80, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// #     Transaction::transaction_start(),
+/// #     Transaction::write_vec(spi::full_addressed_header(0x00, 0x74, AccessMode::Read).to_vec()),
+/// #     Transaction::read_vec(vec![0x00, 0x10, 0x00, 0x00, 0x00]),
+/// #     Transaction::transaction_end(),
+/// # ]);
+/// # let irq = digital::Mock::new(&[digital::Transaction::wait_for_state(digital::State::High)]);
+/// let mut dw3xxx = DW3XXX::new(Async::new(spi)).with_event_wait(IrqPin::new(irq));
+/// 
+/// // SPIRDY is enabled and pending, yet the IRQ line is only awaited once, until TXFRS is raised.
+/// let tx_stamp = block_on(dw3xxx.transmit(&[0x41, 0x88, 0x00], TxOptions::default()))?;
+/// assert_eq!(tx_stamp, 0x1000);
+/// # let (bus, irq, _) = dw3xxx.decompose();
+/// # bus.release().done();
+/// # irq.release().done();
+/// # }
+/// # Ok::<(), dw3xxx::hl::TransmitCommandError<embedded_hal::spi::ErrorKind>>(())
+/// ```
+/// 
+/// This is only available with the `async` feature enabled.
+/// 
+#[cfg(feature = "async")]
+pub struct IrqPin<PIN> {
+    pin: PIN
+}
+
+#[cfg(feature = "async")]
+impl<PIN> IrqPin<PIN> {
+    /// Constructs a new instance of [`IrqPin`].
+    pub fn new(pin: PIN) -> Self {
+        Self { pin }
+    }
+
+    /// Releases the underlying pin.
+    pub fn release(self) -> PIN {
+        self.pin
+    }
+}
+
+#[cfg(feature = "async")]
+impl<PIN: embedded_hal_async::digital::Wait> EventWait for IrqPin<PIN> {
+    async fn wait(&mut self) -> Result<(), ErrorKind> {
+        use embedded_hal::digital::Error;
+
+        self.pin.wait_for_high().await.map_err(|error| error.kind())
+    }
+}
+
+/// 
+/// An [`EventWait`] that polls the device at a fixed interval without blocking.
+/// 
+/// This is the same as [`Polling`], except that the interval is awaited with an [`embedded_hal_async::delay::DelayNs`], such that
+/// other tasks may run while waiting. This is intended for use with the [`Async`](super::Async) bus.
+/// 
+/// This is only available with the `async` feature enabled.
+/// 
+#[cfg(feature = "async")]
+pub struct AsyncPolling<DELAY> {
+    delay: DELAY,
+    interval_us: u32
+}
+
+#[cfg(feature = "async")]
+impl<DELAY> AsyncPolling<DELAY> {
+    /// Constructs a new instance of [`AsyncPolling`] with the polling interval in microseconds.
+    pub fn new(delay: DELAY, interval_us: u32) -> Self {
+        Self { delay, interval_us }
+    }
+
+    /// The polling interval in microseconds.
+    pub fn interval_us(&self) -> u32 {
+        self.interval_us
+    }
+
+    /// Sets the polling interval in microseconds.
+    pub fn set_interval_us(&mut self, interval_us: u32) {
+        self.interval_us = interval_us;
+    }
+
+    /// Releases the underlying delay.
+    pub fn release(self) -> DELAY {
+        self.delay
+    }
+}
+
+#[cfg(feature = "async")]
+impl<DELAY: embedded_hal_async::delay::DelayNs> EventWait for AsyncPolling<DELAY> {
+    async fn wait(&mut self) -> Result<(), ErrorKind> {
+        self.delay.delay_us(self.interval_us).await;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 //! 
 
 /// A fast command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Command {
     /// `CMD_TXRXOFF`
     /// Put the device into `IDLE` state and clear any events.
@@ -63,4 +64,19 @@ pub enum Command {
     /// `CMD_DB_TOGGLE`
     /// Toggle the double buffer pointer and notify the device that the host has finished processing the received buffer/data.
     DbToggle  = 0x13
-}
\ No newline at end of file
+}
+
+impl Command {
+    /// 
+    /// Returns whether the command is a delayed transmission or reception, which begins at a time given by `DX_TIME`.
+    /// 
+    /// Delayed commands may be rejected by the device with the `CMD_ERR` event of `SYS_STATUS`.
+    /// 
+    pub const fn is_delayed(&self) -> bool {
+        matches!(
+            self,
+            Command::Dtx | Command::Drx | Command::DtxTs | Command::DrxTs | Command::DtxRs | Command::DrxRs | Command::DtxRef
+                | Command::DrxRef | Command::DtxW4r | Command::DtxTsW4r | Command::DtxRsW4r | Command::DtxRefW4r
+        )
+    }
+}
@@ -1,13 +1,34 @@
 //! Interrupts for the DW3XXX.
 //! 
+//! Each event of the [`SYS_STATUS`] register, and the matching enable bit of the
+//! [`SYS_ENABLE`](crate::ll::reg::SYS_ENABLE) register, is represented by an [`Interrupt`], and any combination of them by an
+//! [`InterruptSet`].
+//! 
+//! ```rust
+//! # use dw3xxx::ll::interrupts::{Interrupt, InterruptSet};
+//! # use dw3xxx::ll::reg::{SysEnableValue, SysStatusValue};
+//! let enabled = InterruptSet::TX_EVENTS | InterruptSet::RX_ERRORS | Interrupt::Rxfcg;
+//! let enable = SysEnableValue::from(enabled);
+//! assert!(enable.txfrs_en() && enable.rxfcg_en() && !enable.rxprd_en());
+//! 
+//! let status = InterruptSet::from(SysStatusValue::from([0x80, 0x40, 0x00, 0x00, 0x00, 0x00]));
+//! assert_eq!(status, Interrupt::Txfrs | Interrupt::Rxfcg);
+//! assert!(!status.intersects(InterruptSet::RX_ERRORS));
+//! ```
+//! 
+
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use crate::ll::reg::{FintStatValue, Register, SysEnableValue, SysStatusValue, SYS_STATUS};
 
 /// 
 /// An interrupt from the DW3XXX
 /// 
-/// The enum representation values are equal to the offest of each interrupt bit within the interrupt registers. It should be noted, however,
-/// that the interrupt bits are not continuous within the register, so there are index values for which there is no corresponding interrupt.
-/// Moreover, the interrupt registers span two 32-bit sub-registers, so the indices range from 0 to 64.
+/// The enum representation values are equal to the offset of each interrupt bit within the interrupt registers. It should be
+/// noted, however, that the interrupt bits are not continuous within the register, so there are index values for which there is
+/// no corresponding interrupt. Moreover, the interrupt registers span two 32-bit sub-registers, so the indices range from 0 to 64.
 /// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Interrupt {
     Cplock  = 1,
     Spicrce = 2,
@@ -47,4 +68,362 @@ pub enum Interrupt {
     SpiUnf  = 42,
     Spierr  = 43,
     CcaFail = 44
-}
\ No newline at end of file
+}
+
+impl Interrupt {
+    /// Every interrupt, in order of their bit offsets.
+    pub const ALL: [Interrupt; 38] = [
+        Interrupt::Cplock,  Interrupt::Spicrce, Interrupt::Aat,     Interrupt::Txfrb,   Interrupt::Txprs,
+        Interrupt::Txphs,   Interrupt::Txfrs,   Interrupt::Rxprd,   Interrupt::Rxsfdd,  Interrupt::Ciadone,
+        Interrupt::Rxphd,   Interrupt::Rxphe,   Interrupt::Rxfr,    Interrupt::Rxfcg,   Interrupt::Rxfce,
+        Interrupt::Rxfsl,   Interrupt::Rxfto,   Interrupt::Ciaerr,  Interrupt::Vwarn,   Interrupt::Rxovrr,
+        Interrupt::Rxpto,   Interrupt::Spirdy,  Interrupt::Rcinit,  Interrupt::PllHilo, Interrupt::Rxsto,
+        Interrupt::Hpdwarn, Interrupt::Cperr,   Interrupt::Arfe,    Interrupt::Rxprej,  Interrupt::VtDet,
+        Interrupt::Gpioirq, Interrupt::AesDone, Interrupt::AesErr,  Interrupt::CmdErr,  Interrupt::SpiOvf,
+        Interrupt::SpiUnf,  Interrupt::Spierr,  Interrupt::CcaFail,
+    ];
+
+    /// The offset of the interrupt's bit within the interrupt registers.
+    pub const fn bit(self) -> u32 {
+        self as u32
+    }
+
+    /// The mask of the interrupt's bit within the interrupt registers.
+    pub const fn mask(self) -> u64 {
+        1 << self.bit()
+    }
+}
+
+/// 
+/// A set of interrupts.
+/// 
+/// The set is stored as a mask in the same layout as the [`SYS_STATUS`] and [`SYS_ENABLE`](crate::ll::reg::SYS_ENABLE) registers,
+/// such that it may be converted to and from the values of those registers directly.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InterruptSet(u64);
+
+impl InterruptSet {
+    /// The empty set.
+    pub const EMPTY: InterruptSet = InterruptSet(0);
+
+    /// Every interrupt.
+    pub const ALL: InterruptSet = InterruptSet::from_slice(&Interrupt::ALL);
+
+    /// The events raised as a frame is transmitted (`TXFRB`, `TXPRS`, `TXPHS`, and `TXFRS`).
+    pub const TX_EVENTS: InterruptSet = InterruptSet::from_slice(&[
+        Interrupt::Txfrb, Interrupt::Txprs, Interrupt::Txphs, Interrupt::Txfrs
+    ]);
+
+    /// The events raised as a good frame is received (`RXPRD`, `RXSFDD`, `CIADONE`, `RXPHD`, `RXFR`, and `RXFCG`).
+    pub const RX_GOOD: InterruptSet = InterruptSet::from_slice(&[
+        Interrupt::Rxprd, Interrupt::Rxsfdd, Interrupt::Ciadone, Interrupt::Rxphd, Interrupt::Rxfr, Interrupt::Rxfcg
+    ]);
+
+    /// The receiver timeouts (`RXFTO` and `RXPTO`).
+    pub const RX_TIMEOUTS: InterruptSet = InterruptSet::from_slice(&[
+        Interrupt::Rxfto, Interrupt::Rxpto
+    ]);
+
+    /// The receiver errors (`RXPHE`, `RXFCE`, `RXFSL`, `RXSTO`, `ARFE`, `CIAERR`, `CPERR`, `RXOVRR`, and `RXPREJ`).
+    pub const RX_ERRORS: InterruptSet = InterruptSet::from_slice(&[
+        Interrupt::Rxphe, Interrupt::Rxfce, Interrupt::Rxfsl, Interrupt::Rxsto, Interrupt::Arfe,
+        Interrupt::Ciaerr, Interrupt::Cperr, Interrupt::Rxovrr, Interrupt::Rxprej
+    ]);
+
+    /// The SPI errors (`SPICRCE`, `SPI_OVF`, `SPI_UNF`, and `SPIERR`).
+    pub const SPI_ERRORS: InterruptSet = InterruptSet::from_slice(&[
+        Interrupt::Spicrce, Interrupt::SpiOvf, Interrupt::SpiUnf, Interrupt::Spierr
+    ]);
+
+    /// Constructs a set from a mask, discarding any bits that do not correspond to an interrupt.
+    pub const fn from_bits_truncate(bits: u64) -> InterruptSet {
+        InterruptSet(bits & InterruptSet::ALL.0)
+    }
+
+    /// Constructs a set containing each of the interrupts.
+    pub const fn from_slice(interrupts: &[Interrupt]) -> InterruptSet {
+        let mut bits = 0;
+        let mut i = 0;
+
+        while i < interrupts.len() {
+            bits |= interrupts[i].mask();
+            i += 1;
+        }
+
+        InterruptSet(bits)
+    }
+
+    /// The mask of the set, in the layout of the interrupt registers.
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Whether the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether the set contains the interrupt.
+    pub const fn contains(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    /// Whether the set contains every interrupt of the other set.
+    pub const fn contains_all(self, other: InterruptSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether the set contains any interrupt of the other set.
+    pub const fn intersects(self, other: InterruptSet) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// The interrupts in either set.
+    pub const fn union(self, other: InterruptSet) -> InterruptSet {
+        InterruptSet(self.0 | other.0)
+    }
+
+    /// The interrupts in both sets.
+    pub const fn intersection(self, other: InterruptSet) -> InterruptSet {
+        InterruptSet(self.0 & other.0)
+    }
+
+    /// The interrupts in this set but not the other set.
+    pub const fn difference(self, other: InterruptSet) -> InterruptSet {
+        InterruptSet(self.0 & !other.0)
+    }
+
+    /// The interrupts not in this set.
+    pub const fn complement(self) -> InterruptSet {
+        InterruptSet(!self.0 & InterruptSet::ALL.0)
+    }
+
+    /// Adds the interrupt to the set.
+    pub fn insert(&mut self, interrupt: Interrupt) {
+        self.0 |= interrupt.mask();
+    }
+
+    /// Removes the interrupt from the set.
+    pub fn remove(&mut self, interrupt: Interrupt) {
+        self.0 &= !interrupt.mask();
+    }
+
+    /// Iterates over the interrupts in the set, in order of their bit offsets.
+    pub fn iter(self) -> Iter {
+        Iter { set: self, index: 0 }
+    }
+
+    /// Converts the set into a view of the interrupt registers, least significant byte first.
+    pub const fn to_view(self) -> <SYS_STATUS as Register>::RegisterView {
+        let bytes = self.0.to_le_bytes();
+
+        [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]]
+    }
+
+    /// Constructs a set from a view of the interrupt registers, discarding any bits that do not correspond to an interrupt.
+    pub const fn from_view(view: &<SYS_STATUS as Register>::RegisterView) -> InterruptSet {
+        InterruptSet::from_bits_truncate(u64::from_le_bytes([view[0], view[1], view[2], view[3], view[4], view[5], 0, 0]))
+    }
+}
+
+impl From<Interrupt> for InterruptSet {
+    fn from(interrupt: Interrupt) -> Self {
+        InterruptSet(interrupt.mask())
+    }
+}
+
+impl From<SysStatusValue> for InterruptSet {
+    fn from(value: SysStatusValue) -> Self {
+        InterruptSet::from_view(&value.into())
+    }
+}
+
+impl From<InterruptSet> for SysStatusValue {
+    fn from(set: InterruptSet) -> Self {
+        SysStatusValue::from(set.to_view())
+    }
+}
+
+impl From<SysEnableValue> for InterruptSet {
+    fn from(value: SysEnableValue) -> Self {
+        InterruptSet::from_view(&value.into())
+    }
+}
+
+impl From<InterruptSet> for SysEnableValue {
+    fn from(set: InterruptSet) -> Self {
+        SysEnableValue::from(set.to_view())
+    }
+}
+
+impl FromIterator<Interrupt> for InterruptSet {
+    fn from_iter<I: IntoIterator<Item = Interrupt>>(iter: I) -> Self {
+        iter.into_iter().fold(InterruptSet::EMPTY, |set, interrupt| set | interrupt)
+    }
+}
+
+impl IntoIterator for InterruptSet {
+    type Item = Interrupt;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// An iterator over the interrupts in an [`InterruptSet`].
+#[derive(Debug, Clone)]
+pub struct Iter {
+    set: InterruptSet,
+    index: usize
+}
+
+impl Iterator for Iter {
+    type Item = Interrupt;
+
+    fn next(&mut self) -> Option<Interrupt> {
+        while let Some(&interrupt) = Interrupt::ALL.get(self.index) {
+            self.index += 1;
+
+            if self.set.contains(interrupt) {
+                return Some(interrupt);
+            }
+        }
+
+        None
+    }
+}
+
+impl BitOr for InterruptSet {
+    type Output = InterruptSet;
+
+    fn bitor(self, rhs: InterruptSet) -> InterruptSet {
+        self.union(rhs)
+    }
+}
+
+impl BitOr<Interrupt> for InterruptSet {
+    type Output = InterruptSet;
+
+    fn bitor(self, rhs: Interrupt) -> InterruptSet {
+        self.union(rhs.into())
+    }
+}
+
+impl BitOr for Interrupt {
+    type Output = InterruptSet;
+
+    fn bitor(self, rhs: Interrupt) -> InterruptSet {
+        InterruptSet::from(self).union(rhs.into())
+    }
+}
+
+impl BitOrAssign for InterruptSet {
+    fn bitor_assign(&mut self, rhs: InterruptSet) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for InterruptSet {
+    type Output = InterruptSet;
+
+    fn bitand(self, rhs: InterruptSet) -> InterruptSet {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for InterruptSet {
+    fn bitand_assign(&mut self, rhs: InterruptSet) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for InterruptSet {
+    type Output = InterruptSet;
+
+    fn sub(self, rhs: InterruptSet) -> InterruptSet {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for InterruptSet {
+    fn sub_assign(&mut self, rhs: InterruptSet) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for InterruptSet {
+    type Output = InterruptSet;
+
+    fn not(self) -> InterruptSet {
+        self.complement()
+    }
+}
+
+/// 
+/// A category of the [`FINT_STAT`](crate::ll::reg::FINT_STAT) register, which summarises the events of the `SYS_STATUS` register
+/// into a single octet.
+/// 
+/// The enum representation values are equal to the offset of each category bit within the `FINT_STAT` register.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FastInterrupt {
+    /// `TXOK`, a frame has been transmitted.
+    TxOk      = 0,
+    /// `CCA_FAIL`, a transmission was cancelled because a preamble was detected.
+    CcaFail   = 1,
+    /// `RXTSERR`, the CIA could not compute the receive timestamp.
+    RxTsErr   = 2,
+    /// `RXOK`, a frame has been received.
+    RxOk      = 3,
+    /// `RXERR`, an error occurred while receiving a frame.
+    RxErr     = 4,
+    /// `RXTO`, the receiver timed out.
+    RxTo      = 5,
+    /// `SYS_EVENT`, a system event occurred.
+    SysEvent  = 6,
+    /// `SYS_PANIC`, a system error occurred.
+    SysPanic  = 7
+}
+
+impl FastInterrupt {
+    /// Every category, in order of their bit offsets.
+    pub const ALL: [FastInterrupt; 8] = [
+        FastInterrupt::TxOk, FastInterrupt::CcaFail, FastInterrupt::RxTsErr, FastInterrupt::RxOk,
+        FastInterrupt::RxErr, FastInterrupt::RxTo, FastInterrupt::SysEvent, FastInterrupt::SysPanic,
+    ];
+
+    /// The `SYS_STATUS` events summarised by the category.
+    pub const fn events(self) -> InterruptSet {
+        match self {
+            FastInterrupt::TxOk     => InterruptSet::TX_EVENTS,
+            FastInterrupt::CcaFail  => InterruptSet::from_slice(&[Interrupt::Aat, Interrupt::CcaFail]),
+            FastInterrupt::RxTsErr  => InterruptSet::from_slice(&[Interrupt::Ciaerr]),
+            FastInterrupt::RxOk     => InterruptSet::RX_GOOD,
+            FastInterrupt::RxErr    => InterruptSet::from_slice(&[
+                Interrupt::Rxfce, Interrupt::Rxfsl, Interrupt::Rxphe, Interrupt::Arfe, Interrupt::Rxsto, Interrupt::Rxovrr
+            ]),
+            FastInterrupt::RxTo     => InterruptSet::RX_TIMEOUTS,
+            FastInterrupt::SysEvent => InterruptSet::from_slice(&[
+                Interrupt::VtDet, Interrupt::Gpioirq, Interrupt::Rcinit, Interrupt::Spirdy
+            ]),
+            FastInterrupt::SysPanic => InterruptSet::from_slice(&[
+                Interrupt::AesErr, Interrupt::CmdErr, Interrupt::SpiUnf, Interrupt::SpiOvf, Interrupt::Spierr, Interrupt::PllHilo,
+                Interrupt::Vwarn
+            ]),
+        }
+    }
+
+    /// The mask of the category's bit within the `FINT_STAT` register.
+    pub const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Iterates over the categories set in a value of the `FINT_STAT` register.
+    pub fn iter_set(value: FintStatValue) -> impl Iterator<Item = FastInterrupt> {
+        let bits = value.as_ref()[0];
+
+        FastInterrupt::ALL.into_iter().filter(move |category| bits & category.mask() != 0)
+    }
+}
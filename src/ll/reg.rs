@@ -14,6 +14,35 @@
 //! constants and types to statically reference that particular location within the device's register map. For more details see the
 //! documentation for the [`Register`], [`Field`], [`Readable`], and [`Writable`] traits.
 //!
+//! # Reset Values
+//!
+//! Each register also carries its documented reset value as [`Register::RESET_VALUE`], which is used as the [`Default`] of the
+//! register's value type. For an image of the entire register map in its reset state see [`RegisterMap::reset`].
+//!
+//! The reset value is [`None`] for registers where it could not be sourced from the user manual, which includes the memory
+//! buffers whose contents are undefined at power-up, registers that reflect the live state of the device, and registers whose
+//! reset value is loaded from OTP or not documented. The value types of these registers do not implement [`Default`], so any
+//! whole-register write to them must start from an explicit view such as [`Register::ZEROED`].
+//!
+//! # Register Values
+//!
+//! For each register there is also a value type (e.g. [`SysCfgValue`] for [`SYS_CFG`]) that wraps a complete view of the register and
+//! provides a getter and a builder-style setter for each of its fields. This allows several fields to be changed with a single
+//! read-modify-write of the register rather than one for each field.
+//!
+//! ```rust
+//! # use dw3xxx::ll::reg::{SysCfgValue, PhrMode};
+//! let mut value = SysCfgValue::from([0x00; 4]);
+//! value
+//!     .set_ffen(true)
+//!     .set_auto_ack(true)
+//!     .set_phr_mode(PhrMode::Extended);
+//!
+//! assert!(value.ffen());
+//! assert_eq!(value.phr_mode(), Ok(PhrMode::Extended));
+//! assert_eq!(<[u8; 4]>::from(value), [0x11, 0x08, 0x00, 0x00]);
+//! ```
+//!
 
 /// A register of the DW3XXX.
 pub trait Register {
@@ -23,8 +52,14 @@ pub trait Register {
     const SUB_ADDRESS: u8;
     /// The length of the register.
     const LEN: usize;
+    /// The value of the register after the device is reset, as documented in the user manual, or [`None`] if it is unknown.
+    const RESET_VALUE: Option<Self::RegisterView>;
+    /// A view of the register with every bit cleared.
+    const ZEROED: Self::RegisterView;
     /// The type representing a view of the register.
-    type RegisterView;
+    type RegisterView: AsRef<[u8]> + AsMut<[u8]>;
+    /// The type representing a typed value of the register, with getters and setters for each field.
+    type RegisterValue: From<Self::RegisterView> + Into<Self::RegisterView> + AsRef<[u8]> + AsMut<[u8]>;
 }
 
 /// A field within a register of the DW3XXX.
@@ -32,7 +67,7 @@ pub trait Field {
     /// The parent register of the field.
     type Register: Register;
     /// The type level representation of the field.
-    type Value;
+    type Value: FieldValue;
     /// The index of the field's first bit within the register.
     const FIRST_BIT: usize;
     /// The size of the field in bits.
@@ -41,32 +76,264 @@ pub trait Field {
 
 /// A marker trait for a readable field.
 pub trait Readable: Field {
+    ///
     /// Read the field from the register.
+    ///
+    /// ```rust
+    /// # use dw3xxx::ll::reg::{Readable, tx_fctrl, PreambleLength};
+    /// let register: [u8; 6] = [0x7F, 0x1C, 0x00, 0x00, 0x00, 0x00];
+    /// assert_eq!(tx_fctrl::TXFLEN::read(&register), 0x07F);
+    /// assert!(tx_fctrl::TR::read(&register));
+    /// assert_eq!(tx_fctrl::TXPSR::read(&register), Ok(PreambleLength::Symbols64));
+    /// ```
+    ///
+    /// Fields whose value is an enum decode into a [`Result`], where reserved encodings are returned as a [`ReservedValue`].
+    ///
     fn read(
         register: &<<Self as Field>::Register as Register>::RegisterView,
-    ) -> <Self as Field>::Value {
-        todo!()
+    ) -> <<Self as Field>::Value as FieldValue>::Decoded {
+        <Self as Field>::Value::from_bits(read_bits(register.as_ref(), Self::FIRST_BIT, Self::SIZE))
     }
 }
 
 /// A marker trait for a writable field.
 pub trait Writable: Field {
+    ///
     /// Write the field to the register.
+    ///
+    /// Only the bits belonging to the field are modified, all other bits of the register are left untouched. Any bits of the value
+    /// that do not fit within the field are discarded.
+    ///
+    /// ```rust
+    /// # use dw3xxx::ll::reg::{Writable, tx_fctrl, PreambleLength};
+    /// let mut register: [u8; 6] = [0xFF; 6];
+    /// tx_fctrl::TXFLEN::write(&mut register, 0x07F);
+    /// tx_fctrl::TXPSR::write(&mut register, PreambleLength::Symbols64);
+    /// assert_eq!(register, [0x7F, 0x1C, 0xFF, 0xFF, 0xFF, 0xFF]);
+    /// ```
+    ///
     fn write(
         register: &mut <<Self as Field>::Register as Register>::RegisterView,
         value: <Self as Field>::Value,
     ) {
-        todo!()
+        write_bits(register.as_mut(), Self::FIRST_BIT, Self::SIZE, value.into_bits())
+    }
+}
+
+/// A type that can be stored within the bits of a [`Field`].
+pub trait FieldValue: Sized {
+    /// The number of bits that the type is able to hold.
+    const BITS: u32;
+    ///
+    /// The number of bits that a field must have to hold every value of the type, or zero if values may be truncated.
+    ///
+    /// This is only non-zero for enums, where it is the number of bits needed by the largest discriminant.
+    ///
+    const REQUIRED_BITS: u32 = 0;
+
+    /// The type produced when decoding the raw bits of a field.
+    ///
+    /// For the unsigned integer types this is the integer itself, while for enums this is a [`Result`] as not every
+    /// encoding of the raw bits is necessarily valid.
+    type Decoded;
+
+    /// Converts the raw bits of a field into a value.
+    fn from_bits(bits: u128) -> Self::Decoded;
+    /// Converts a value into the raw bits of a field.
+    fn into_bits(self) -> u128;
+}
+
+/// A helper macro to implement the [`FieldValue`] trait for the unsigned integer types.
+macro_rules! impl_field_value {
+    ($($ty:ty),*) => {
+        $(
+            impl FieldValue for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                type Decoded = Self;
+
+                fn from_bits(bits: u128) -> Self {
+                    bits as $ty
+                }
+
+                fn into_bits(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_field_value!(u8, u16, u32, u64, u128);
+
+impl FieldValue for bool {
+    const BITS: u32 = 1;
+
+    type Decoded = Self;
+
+    fn from_bits(bits: u128) -> Self {
+        bits & 1 != 0
+    }
+
+    fn into_bits(self) -> u128 {
+        self as u128
+    }
+}
+
+///
+/// A raw field value that does not correspond to any variant of the field's enum, usually because the encoding is reserved.
+///
+/// ```rust
+/// # use dw3xxx::ll::reg::{PdoaMode, ReservedValue};
+/// assert_eq!(PdoaMode::try_from(0b01), Ok(PdoaMode::Mode1));
+/// assert_eq!(PdoaMode::try_from(0b10), Err(ReservedValue(0b10)));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReservedValue<T>(pub T);
+
+///
+/// Extracts `size` bits starting at bit `first_bit` from a little-endian byte slice.
+///
+/// The bits are numbered such that bit 0 is the least significant bit of the first byte, and the field may freely cross byte
+/// boundaries. Fields of up to 128 bits are supported.
+///
+/// ```rust
+/// # use dw3xxx::ll::reg::read_bits;
+/// assert_eq!(read_bits(&[0b1010_0000, 0b0000_0011], 5, 6), 0b011101);
+/// assert_eq!(read_bits(&[0xFF; 17], 4, 128), u128::MAX);
+/// ```
+///
+/// # Panics
+/// This function panics if the field extends beyond the end of the slice or is larger than 128 bits.
+///
+pub const fn read_bits(bytes: &[u8], first_bit: usize, size: u8) -> u128 {
+    assert!(size <= 128, "fields may not be larger than 128 bits");
+
+    let size = size as usize;
+    let mut value: u128 = 0;
+    let mut done = 0;
+
+    while done < size {
+        let bit = first_bit + done;
+        let offset = bit % 8;
+        let count = min(8 - offset, size - done);
+        let chunk = (bytes[bit / 8] >> offset) & low_mask(count);
+
+        value |= (chunk as u128) << done;
+        done += count;
+    }
+
+    value
+}
+
+///
+/// Inserts the lowest `size` bits of `value` starting at bit `first_bit` into a little-endian byte slice.
+///
+/// The bits are numbered such that bit 0 is the least significant bit of the first byte, and the field may freely cross byte
+/// boundaries. Fields of up to 128 bits are supported. Bits outside of the field are left untouched.
+///
+/// ```rust
+/// # use dw3xxx::ll::reg::write_bits;
+/// let mut bytes = [0u8; 2];
+/// write_bits(&mut bytes, 5, 6, 0b011101);
+/// assert_eq!(bytes, [0b1010_0000, 0b0000_0011]);
+/// ```
+///
+/// # Panics
+/// This function panics if the field extends beyond the end of the slice or is larger than 128 bits.
+///
+pub const fn write_bits(bytes: &mut [u8], first_bit: usize, size: u8, value: u128) {
+    assert!(size <= 128, "fields may not be larger than 128 bits");
+
+    let size = size as usize;
+    let mut done = 0;
+
+    while done < size {
+        let bit = first_bit + done;
+        let offset = bit % 8;
+        let count = min(8 - offset, size - done);
+        let mask = low_mask(count) << offset;
+        let chunk = ((value >> done) as u8) << offset;
+
+        bytes[bit / 8] = (bytes[bit / 8] & !mask) | (chunk & mask);
+        done += count;
     }
 }
 
+/// Returns a mask with the lowest `count` bits set, where `count` is at most 8.
+const fn low_mask(count: usize) -> u8 {
+    (u8::MAX as u16 >> (8 - count)) as u8
+}
+
+/// Converts the reset value of a register into a view of the register, least significant byte first.
+///
+/// Registers that are longer than 16 bytes hold at most 16 bytes of reset value, so the remaining bytes are left zeroed.
+const fn reset_view<const N: usize>(value: u128) -> [u8; N] {
+    let bytes = value.to_le_bytes();
+    let mut view = [0; N];
+    let mut i = 0;
+
+    while i < min(N, bytes.len()) {
+        view[i] = bytes[i];
+        i += 1;
+    }
+
+    view
+}
+
+/// Checks that none of the given fields, as `(FIRST_BIT, SIZE, ALIAS)`, overlap while ignoring any fields marked as an alias.
+const fn fields_disjoint(fields: &[(usize, usize, bool)]) -> bool {
+    let mut i = 0;
+
+    while i < fields.len() {
+        let mut j = i + 1;
+
+        while j < fields.len() {
+            let (a_start, a_size, a_alias) = fields[i];
+            let (b_start, b_size, b_alias) = fields[j];
+
+            if !a_alias && !b_alias && a_start < b_start + b_size && b_start < a_start + a_size {
+                return false;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns the number of bits needed to hold the largest of the given values.
+const fn required_bits(values: &[u128]) -> u32 {
+    let mut max = 0;
+    let mut i = 0;
+
+    while i < values.len() {
+        if values[i] > max {
+            max = values[i];
+        }
+
+        i += 1;
+    }
+
+    u128::BITS - max.leading_zeros()
+}
+
+/// A `const` equivalent of [`core::cmp::min`].
+const fn min(a: usize, b: usize) -> usize {
+    if a < b { a } else { b }
+}
+
 ///
 /// A helper macro to generate structured register and field structs organized into modules.
 ///
 /// The declaration for each register is structured as follows:
 /// ```markdown
 /// /// Register documentation
-/// [{BASE_ADDRESS}, {SUB_ADDRESS}, {LENGTH}, {RO, WO or RW}, NAME(name)] {
+/// [{BASE_ADDRESS}, {SUB_ADDRESS}, {LENGTH}, {RO, WO or RW}, NAME(name), {RESET_VALUE or ?}] {
 ///     /// Field documentation
 ///     FIELD_NAME, [FIRST_BIT], [SIZE], [TYPE]
 ///     /// Field documentation
@@ -76,6 +343,24 @@ pub trait Writable: Field {
 /// }
 /// ```
 ///
+/// The reset value is given as `?` where it is unknown, in which case the register has no [`Default`] value.
+///
+/// The layout of each register is validated at compile time, such that every field must fit within both the register and its
+/// type, every discriminant of an enum type must fit within the field, and no two fields may overlap. Fields that intentionally
+/// overlap other fields (e.g. a field spanning the entire register) must be marked as an alias by following the type with `, alias`.
+///
+/// Additionally, a field may declare an enum as its type by following the type name with the enum's representation and its
+/// variants. The enum is generated at the top level of this module so that it may be reused as the type of other fields.
+/// ```markdown
+/// /// Field documentation
+/// FIELD_NAME, [FIRST_BIT], [SIZE], [TYPE]([REPR]) {
+///     /// Variant documentation
+///     VariantName = [VALUE],
+///
+///     ...
+/// }
+/// ```
+///
 macro_rules! impl_registers {
     (
         $(
@@ -86,7 +371,8 @@ macro_rules! impl_registers {
             $sub_address:expr,
             $reg_len:expr,
             $rw:tt,
-            $reg_name:ident($reg_name_lower:ident)] {
+            $reg_name:ident($reg_name_lower:ident),
+            $reset_value:tt] {
             $(
                 $(
                     #[$field_doc:meta]
@@ -94,39 +380,149 @@ macro_rules! impl_registers {
                 $field_name:ident,
                 $field_start:expr,
                 $field_size:expr,
-                $field_ty:ty;
+                $field_ty:ident
+                $(
+                    ($field_repr:ident) {
+                        $(
+                            $(
+                                #[$variant_doc:meta]
+                            )*
+                            $variant:ident = $variant_value:expr
+                        ),*
+                        $(,)?
+                    }
+                )?
+                $(, $field_alias:ident)?;
             )*
             }
         )*
     ) => {
         $(
             $(
-                #[$doc]
-            )*
-            #[doc = ""]
-            #[doc = " # Fields"]
-            #[doc = ""]
-            #[doc = concat!(" For access to the fields of this register see the [`", stringify!($reg_name_lower), "`] module.  ")]
-            #[doc = ""]
-            #[doc = " ## Quick Reference"]
-            #[doc = ""]
-            $(
-                #[doc = concat!(" [`", stringify!($field_name), "`](", stringify!($reg_name_lower), "::", stringify!($field_name), ")  ")]
+                $(
+                    impl_field_enum!(
+                        [$field_ty($field_repr), $reg_name_lower::$field_name] {
+                            $(
+                                $(
+                                    #[$variant_doc]
+                                )*
+                                $variant = $variant_value,
+                            )*
+                        }
+                    );
+                )?
             )*
-            #[doc = ""]
-            #[allow(non_camel_case_types)]
-            pub struct $reg_name;
 
-            impl Register for $reg_name {
-                const BASE_ADDRESS: u8    = $base_address;
-                const SUB_ADDRESS:  u8    = $sub_address;
-                const LEN:          usize = $reg_len;
+            paste::paste! {
+                $(
+                    #[$doc]
+                )*
+                #[doc = ""]
+                #[doc = " # Fields"]
+                #[doc = ""]
+                #[doc = concat!(" For access to the fields of this register see the [`", stringify!($reg_name_lower), "`] module.  ")]
+                #[doc = concat!(" For typed access to a whole value of this register see [`", stringify!([<$reg_name_lower:camel Value>]), "`].")]
+                #[doc = ""]
+                #[doc = " ## Quick Reference"]
+                #[doc = ""]
+                $(
+                    #[doc = concat!(" [`", stringify!($field_name), "`](", stringify!($reg_name_lower), "::", stringify!($field_name), ")  ")]
+                )*
+                #[doc = ""]
+                #[allow(non_camel_case_types)]
+                pub struct $reg_name;
+
+                impl Register for $reg_name {
+                    const BASE_ADDRESS: u8    = $base_address;
+                    const SUB_ADDRESS:  u8    = $sub_address;
+                    const LEN:          usize = $reg_len;
+
+                    const RESET_VALUE: Option<Self::RegisterView> = reset_value!($reset_value);
+                    const ZEROED: Self::RegisterView          = [0; $reg_len];
+
+                    type RegisterView         = [u8; $reg_len];
+                    type RegisterValue        = [<$reg_name_lower:camel Value>];
+                }
+
+                #[doc = concat!(" A typed value of the register [`", stringify!($reg_name), "`].")]
+                #[doc = ""]
+                #[doc = " Each readable field has a getter named after the field, and each writable field has a builder-style setter prefixed"]
+                #[doc = " with `set_`, such that several fields can be changed before the value is written back to the device."]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                pub struct [<$reg_name_lower:camel Value>]([u8; $reg_len]);
+
+                impl [<$reg_name_lower:camel Value>] {
+                    $(
+                        impl_accessors!(
+                            $rw,
+                            $reg_name_lower::$field_name,
+                            [<$field_name:lower>],
+                            [<set_ $field_name:lower>],
+                            $field_ty
+                        );
+                    )*
+                }
+
+                impl From<[u8; $reg_len]> for [<$reg_name_lower:camel Value>] {
+                    fn from(view: [u8; $reg_len]) -> Self {
+                        Self(view)
+                    }
+                }
+
+                impl From<[<$reg_name_lower:camel Value>]> for [u8; $reg_len] {
+                    fn from(value: [<$reg_name_lower:camel Value>]) -> Self {
+                        value.0
+                    }
+                }
+
+                impl AsRef<[u8]> for [<$reg_name_lower:camel Value>] {
+                    fn as_ref(&self) -> &[u8] {
+                        &self.0
+                    }
+                }
+
+                impl AsMut<[u8]> for [<$reg_name_lower:camel Value>] {
+                    fn as_mut(&mut self) -> &mut [u8] {
+                        &mut self.0
+                    }
+                }
 
-                type RegisterView         = [u8; $reg_len];
+                impl_reset_default!($reset_value, [<$reg_name_lower:camel Value>]);
             }
 
+            #[allow(clippy::int_plus_one)]
+            const _: () = {
+                assert!(
+                    reset_fits!($reset_value, $reg_len),
+                    concat!("the reset value of ", stringify!($reg_name), " does not fit within the register")
+                );
+
+                $(
+                    assert!(
+                        $field_start + $field_size <= $reg_len * 8,
+                        concat!("the field ", stringify!($reg_name_lower), "::", stringify!($field_name), " does not fit within the register")
+                    );
+                    assert!(
+                        $field_size <= <$field_ty as FieldValue>::BITS,
+                        concat!("the field ", stringify!($reg_name_lower), "::", stringify!($field_name), " does not fit within its value type")
+                    );
+                    assert!(
+                        $field_size >= <$field_ty as FieldValue>::REQUIRED_BITS,
+                        concat!("the values of ", stringify!($field_ty), " do not fit within the field ", stringify!($reg_name_lower), "::", stringify!($field_name))
+                    );
+                )*
+
+                assert!(
+                    fields_disjoint(&[$(($field_start, $field_size, is_alias!($($field_alias)?))),*]),
+                    concat!("the fields of ", stringify!($reg_name), " overlap")
+                );
+            };
+
             #[doc = concat!(" Types for the fields within the register [`", stringify!($reg_name), "`].")]
             pub mod $reg_name_lower {
+                #[allow(unused_imports)]
+                use super::*;
+
                 $(
                     $(
                         #[$field_doc]
@@ -151,6 +547,202 @@ macro_rules! impl_registers {
                 )*
             }
         )*
+
+        ///
+        /// An image of the entire register map of the device.
+        ///
+        /// Each register is stored as a view in the field of the same name, so that an image can be compared against values read
+        /// from the device, used to restore the device's configuration, or used to seed a simulation of the device. A register
+        /// whose contents are unknown, such as one without a documented [reset value](Register::RESET_VALUE), is [`None`].
+        ///
+        /// ```rust
+        /// # use dw3xxx::ll::reg::{RegisterMap, Register, DEV_ID, PLL_CC, SYS_CFG};
+        /// let map = RegisterMap::reset();
+        ///
+        /// assert_eq!(map.sys_cfg, SYS_CFG::RESET_VALUE);
+        /// assert_eq!(map.get(DEV_ID::BASE_ADDRESS, DEV_ID::SUB_ADDRESS), Some(&[0x02, 0x03, 0xCA, 0xDE][..]));
+        /// assert_eq!(map.get(PLL_CC::BASE_ADDRESS, PLL_CC::SUB_ADDRESS), None);
+        /// ```
+        ///
+        /// <div class="warning">
+        /// This type contains a copy of every register including the large memory buffers, and as such is roughly 15 KiB in size.
+        /// </div>
+        ///
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct RegisterMap {
+            $(
+                #[doc = concat!(" A view of the register [`", stringify!($reg_name), "`].")]
+                pub $reg_name_lower: Option<[u8; $reg_len]>,
+            )*
+        }
+
+        impl RegisterMap {
+            /// Builds an image of the register map with every register in its reset state, where the reset state is known.
+            pub const fn reset() -> Self {
+                Self {
+                    $(
+                        $reg_name_lower: <$reg_name as Register>::RESET_VALUE,
+                    )*
+                }
+            }
+
+            /// Returns the view of the register at the given address, if there is one and its contents are known.
+            ///
+            /// Where registers overlap, the first register declared at the address is returned.
+            pub fn get(&self, base_address: u8, sub_address: u8) -> Option<&[u8]> {
+                $(
+                    if base_address == <$reg_name as Register>::BASE_ADDRESS && sub_address == <$reg_name as Register>::SUB_ADDRESS {
+                        return self.$reg_name_lower.as_ref().map(|view| &view[..]);
+                    }
+                )*
+
+                None
+            }
+
+            /// Returns the mutable view of the register at the given address, if there is one and its contents are known.
+            ///
+            /// Where registers overlap, the first register declared at the address is returned. The contents of a register that
+            /// are unknown can be set by assigning its field directly.
+            pub fn get_mut(&mut self, base_address: u8, sub_address: u8) -> Option<&mut [u8]> {
+                $(
+                    if base_address == <$reg_name as Register>::BASE_ADDRESS && sub_address == <$reg_name as Register>::SUB_ADDRESS {
+                        return self.$reg_name_lower.as_mut().map(|view| &mut view[..]);
+                    }
+                )*
+
+                None
+            }
+        }
+
+        impl Default for RegisterMap {
+            fn default() -> Self {
+                Self::reset()
+            }
+        }
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to determine whether a field is marked as an alias.
+macro_rules! is_alias {
+    () => {
+        false
+    };
+    (alias) => {
+        true
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to convert a reset value, or `?` if it is unknown, into a view.
+macro_rules! reset_value {
+    (?) => {
+        None
+    };
+    ($reset_value:literal) => {
+        Some(reset_view($reset_value))
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to check that a reset value fits within its register.
+macro_rules! reset_fits {
+    (?, $reg_len:expr) => {
+        true
+    };
+    ($reset_value:literal, $reg_len:expr) => {
+        $reg_len >= 16 || ($reset_value as u128) >> ($reg_len * 8) == 0
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to implement [`Default`] as the reset value, where it is known.
+macro_rules! impl_reset_default {
+    (?, $value:ident) => {};
+    ($reset_value:literal, $value:ident) => {
+        impl Default for $value {
+            /// Returns the reset value of the register.
+            fn default() -> Self {
+                Self(reset_view($reset_value))
+            }
+        }
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to generate the enum used as the value of a field.
+macro_rules! impl_field_enum {
+    (
+        [$name:ident($repr:ident), $reg_name_lower:ident::$field_name:ident] {
+            $(
+                $(
+                    #[$variant_doc:meta]
+                )*
+                $variant:ident = $value:expr,
+            )*
+        }
+    ) => {
+        #[doc = concat!(" The value of the [`", stringify!($field_name), "`](", stringify!($reg_name_lower), "::", stringify!($field_name), ") field.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr($repr)]
+        pub enum $name {
+            $(
+                $(
+                    #[$variant_doc]
+                )*
+                $variant = $value,
+            )*
+        }
+
+        impl TryFrom<$repr> for $name {
+            type Error = ReservedValue<$repr>;
+
+            fn try_from(value: $repr) -> Result<Self, Self::Error> {
+                match value {
+                    $(
+                        value if value == $name::$variant as $repr => Ok($name::$variant),
+                    )*
+                    value => Err(ReservedValue(value)),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> Self {
+                value as $repr
+            }
+        }
+
+        impl FieldValue for $name {
+            const BITS: u32          = <$repr>::BITS;
+            const REQUIRED_BITS: u32 = required_bits(&[$($name::$variant as u128),*]);
+
+            type Decoded = Result<Self, ReservedValue<$repr>>;
+
+            fn from_bits(bits: u128) -> Self::Decoded {
+                Self::try_from(bits as $repr)
+            }
+
+            fn into_bits(self) -> u128 {
+                self as $repr as u128
+            }
+        }
+    };
+}
+
+/// A helper macro for use in the [`impl_registers`] macro to generate the field getters and setters of a register's value type.
+macro_rules! impl_accessors {
+    (RW, $reg_name_lower:ident::$field_name:ident, $getter:ident, $setter:ident, $field_ty:ident) => {
+        impl_accessors!(RO, $reg_name_lower::$field_name, $getter, $setter, $field_ty);
+        impl_accessors!(WO, $reg_name_lower::$field_name, $getter, $setter, $field_ty);
+    };
+    (RO, $reg_name_lower:ident::$field_name:ident, $getter:ident, $setter:ident, $field_ty:ident) => {
+        #[doc = concat!(" Reads the [`", stringify!($field_name), "`](", stringify!($reg_name_lower), "::", stringify!($field_name), ") field.")]
+        pub fn $getter(&self) -> <$field_ty as FieldValue>::Decoded {
+            <$reg_name_lower::$field_name as Readable>::read(&self.0)
+        }
+    };
+    (WO, $reg_name_lower:ident::$field_name:ident, $getter:ident, $setter:ident, $field_ty:ident) => {
+        #[doc = concat!(" Writes the [`", stringify!($field_name), "`](", stringify!($reg_name_lower), "::", stringify!($field_name), ") field.")]
+        pub fn $setter(&mut self, value: $field_ty) -> &mut Self {
+            <$reg_name_lower::$field_name as Writable>::write(&mut self.0, value);
+            self
+        }
     };
 }
 
@@ -170,7 +762,7 @@ macro_rules! impl_rw {
 
 impl_registers! {
     /// Device identifier
-    [0x00, 0x00, 4, RO, DEV_ID(dev_id)] {
+    [0x00, 0x00, 4, RO, DEV_ID(dev_id), 0xDECA_0302] {
         /// Revision
         REV, 0, 4,  u8;
         /// Version
@@ -181,51 +773,72 @@ impl_registers! {
         RIDTAG, 16, 16,  u16;
     }
     /// Extended Unique Identifier
-    [0x00, 0x04, 8, RW, EUI(eui)] {
+    [0x00, 0x04, 8, RW, EUI(eui), 0xFFFF_FFFF_0000_0000] {
         /// Extended Unique Identifier
         VALUE, 0, 64,  u64;
     }
     /// PAN Identifier and Short Address
-    [0x00, 0x0C, 4, RW, PANADR(panadr)] {
+    [0x00, 0x0C, 4, RW, PANADR(panadr), 0xFFFF_FFFF] {
         /// Short Address
         SHORT_ADDR, 0, 16,  u16;
         /// PAN Identifier
         PAN_ID, 16, 16,  u16;
     }
     /// System Configuration
-    [0x00, 0x10, 4, RW, SYS_CFG(sys_cfg)] {
+    [0x00, 0x10, 4, RW, SYS_CFG(sys_cfg), 0x0000_0688] {
         /// Frame Filtering Enable
-        FFEN, 0, 1,  u8;
+        FFEN, 0, 1,  bool;
         /// disable auto-FCS Transmission
-        DIS_FCS_TX, 1, 1,  u8;
+        DIS_FCS_TX, 1, 1,  bool;
         /// Disable frame check error handling
-        DIS_FCE, 2, 1,  u8;
+        DIS_FCE, 2, 1,  bool;
         /// Disable Double RX Buffer
-        DIS_DRXB, 3, 1,  u8;
+        DIS_DRXB, 3, 1,  bool;
         /// PHR Mode
-        PHR_MODE, 4, 1,  u8;
+        PHR_MODE, 4, 1,  PhrMode(u8) {
+            /// Standard IEEE 802.15.4 PHR, allowing frames of up to 127 octets.
+            Standard = 0b0,
+            /// Decawave proprietary extended PHR, allowing frames of up to 1023 octets.
+            Extended = 0b1,
+        };
         /// Sets the PHR rate to match the data rate
-        PHR_6M8, 5, 1,  u8;
+        PHR_6M8, 5, 1,  bool;
         /// Enable SPI CRC functionnality
-        SPI_CRCEN, 6, 1,  u8;
+        SPI_CRCEN, 6, 1,  bool;
         /// Select CIA processing preamble CIR
-        CIA_IPATOV, 7, 1,  u8;
+        CIA_IPATOV, 7, 1,  bool;
         /// Select CIA processing STS CIR
-        CIA_STS, 8, 1,  u8;
+        CIA_STS, 8, 1,  bool;
         /// Receive Wait Timeout Enable
-        RXWTOE, 9, 1,  u8;
+        RXWTOE, 9, 1,  bool;
         /// Receiver Auto Re-enable
-        RXAUTR, 10, 1,  u8;
+        RXAUTR, 10, 1,  bool;
         /// Automatic Acknowledge Enable
-        AUTO_ACK, 11, 1,  u8;
+        AUTO_ACK, 11, 1,  bool;
         /// STS Packet Configuration
-        CP_SPC, 12, 2,  u8;
+        CP_SPC, 12, 2,  StsPacketConfig(u8) {
+            /// No STS is transmitted (SP0).
+            Off = 0b00,
+            /// The STS is transmitted after the SFD and before the PHR (SP1).
+            Sp1 = 0b01,
+            /// The STS is transmitted after the data payload (SP2).
+            Sp2 = 0b10,
+            /// The STS is transmitted with no PHR or data payload (SP3).
+            Sp3 = 0b11,
+        };
         /// configures the SDC
-        CP_SDC, 15, 1,  u8;
+        CP_SDC, 15, 1,  bool;
         /// configure PDoA
-        PDOA_MODE, 16, 2,  u8;
+        PDOA_MODE, 16, 2,  PdoaMode(u8) {
+            /// PDoA is disabled.
+            Off = 0b00,
+            /// PDoA mode 1, the phase difference is measured between the preamble and the STS.
+            Mode1 = 0b01,
+            /// PDoA mode 3, the phase difference is measured between the two halves of the STS.
+            Mode3 = 0b11,
+        };
         /// enable fast RX to TX turn around mode
-        FAST_AAT, 18, 1,  u8;
+        FAST_AAT, 18, 1,  bool;
     }
     ///
     /// Frame Filter Configuration Bit Map
@@ -254,63 +867,63 @@ impl_registers! {
     /// For more information on frame filtering please see the [DW3000 User Manual](https://www.qorvo.com/products/d/da008154)
     /// section 5.4 "Frame filtering".
     ///
-    [0x00, 0x14, 2, RW, FF_CFG(ff_cfg)] {
+    [0x00, 0x14, 2, RW, FF_CFG(ff_cfg), 0x00] {
         ///
         /// Frame Filtering Allow Beacon
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Beacon" type (binary 000) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAB, 0, 1,  u8;
+        FFAB, 0, 1,  bool;
         ///
         /// Frame Filtering Allow Data
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Data" type (binary 001) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAD, 1, 1,  u8;
+        FFAD, 1, 1,  bool;
         ///
         /// Frame Filtering Allow Acknowledgement
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Acknowledgement" type (binary 010) in
         /// their header. For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAA, 2, 1,  u8;
+        FFAA, 2, 1,  bool;
         ///
         /// Frame Filtering Allow MAC Command Frame
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "MAC" type (binary 011) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAM, 3, 1,  u8;
+        FFAM, 3, 1,  bool;
         ///
         /// Frame Filtering Allow Reserved
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Reserved" type (binary 100) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAR, 4, 1,  u8;
+        FFAR, 4, 1,  bool;
         ///
         /// Frame Filtering Allow Multipurpose
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Multipurpose" type (binary 101) in
         /// their header. For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAMULTI, 5, 1,  u8;
+        FFAMULTI, 5, 1,  bool;
         ///
         /// Frame Filtering Allow Fragmented
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Fragmented" type (binary 110) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAF, 6, 1,  u8;
+        FFAF, 6, 1,  bool;
         ///
         /// Frame Filtering Allow Extended
         ///
         /// When this bit is 0 the frame filtering will ignore frames with the "Extended" type (binary 111) in their header.
         /// For more information see [FF_CFG](super::FF_CFG).
         ///
-        FFAE, 7, 1,  u8;
+        FFAE, 7, 1,  bool;
         ///
         /// Frame Filtering Behave As Coordinator
         ///
@@ -319,7 +932,7 @@ impl_registers! {
         /// * For "MAC" and "Data" type frames the source PAN ID must match that set in [PAN_ID](super::panadr::PAN_ID)
         /// * For "Multipurpose" type frames the destination PAN ID must match that set in [PAN_ID](super::panadr::PAN_ID)
         ///
-        FFBC, 8, 1,  u8;
+        FFBC, 8, 1,  bool;
         ///
         /// Frame Filtering Allow MAC Implicit Broadcast
         ///
@@ -328,7 +941,7 @@ impl_registers! {
         /// rejected. When this bit is 1, then frames without a destination PAN ID and destination address are treated as
         /// though they are addressed to the broadcast PAN ID and broadcast short (16-bit) address.
         ///
-        FFIB, 9, 1,  u8;
+        FFIB, 9, 1,  bool;
         ///
         /// Data pending for device at LE0 addr
         ///
@@ -339,7 +952,7 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [SYS_CFG](super::SYS_CFG) register.
         ///
-        LE0_PEND, 10, 1,  u8;
+        LE0_PEND, 10, 1,  bool;
         /// Data pending for device at LE1 addr
         ///
         /// When this bit is 1 and the device receives a Data Request MAC Command frame whose source address matches the address
@@ -349,7 +962,7 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [SYS_CFG](super::SYS_CFG) register.
         ///
-        LE1_PEND, 11, 1,  u8;
+        LE1_PEND, 11, 1,  bool;
         ///
         /// Data pending for device at LE2 addr
         ///
@@ -360,7 +973,7 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [SYS_CFG](super::SYS_CFG) register.
         ///
-        LE2_PEND, 12, 1,  u8;
+        LE2_PEND, 12, 1,  bool;
         ///
         /// Data pending for device at LE3 addr
         ///
@@ -371,7 +984,7 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [SYS_CFG](super::SYS_CFG) register.
         ///
-        LE3_PEND, 13, 1,  u8;
+        LE3_PEND, 13, 1,  bool;
         ///
         /// Short Source Address Data Request Acknowledge With Pending
         ///
@@ -385,7 +998,7 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [SYS_CFG](super::SYS_CFG) register.
         ///
-        SSADRAPE, 14, 1,  u8;
+        SSADRAPE, 14, 1,  bool;
         ///
         /// Long Source Address Data Request Acknowledge With Pending
         ///
@@ -396,15 +1009,15 @@ impl_registers! {
         /// Note: in order for this field to have an effect, automatic acknowledgement must be enabled by setting the
         /// [AUTO_ACK](super::sys_cfg::AUTO_ACK) bit of the [super::SYS_CFG] register.
         ///
-        LSADRAPE, 15, 1,  u8;
+        LSADRAPE, 15, 1,  bool;
     }
     /// SPI CRC read status
-    [0x00, 0x18, 1, RO, SPI_RD_CRC(spi_rd_crc)] {
+    [0x00, 0x18, 1, RO, SPI_RD_CRC(spi_rd_crc), ?] {
         /// SPI CRC read status
         VALUE, 0, 8,  u8;
     }
     ///  System Time Counter register
-    [0x00, 0x1C, 4, RO, SYS_TIME(sys_time)] {
+    [0x00, 0x1C, 4, RO, SYS_TIME(sys_time), ?] {
         /// System Time Counter register
         VALUE, 0, 32,  u32;
     }
@@ -415,15 +1028,39 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x24, 6, RW, TX_FCTRL(tx_fctrl)] {
+    [0x00, 0x24, 6, RW, TX_FCTRL(tx_fctrl), 0x0000_0000_1C0C] {
         /// TX Frame Length
         TXFLEN, 0, 10,  u16;
         /// Transmit Bit Rate
-        TXBR, 10, 1,  u8;
+        TXBR, 10, 1,  DataRate(u8) {
+            /// 850 kb/s
+            Rate850K = 0b0,
+            /// 6.8 Mb/s
+            Rate6M8 = 0b1,
+        };
         /// Transmit Ranging enable
-        TR, 11, 1,  u8;
+        TR, 11, 1,  bool;
         /// Transmit Preamble Symbol Repetitions
-        TXPSR, 12, 4,  u8;
+        TXPSR, 12, 4,  PreambleLength(u8) {
+            /// 32 preamble symbols
+            Symbols32 = 0b0100,
+            /// 64 preamble symbols
+            Symbols64 = 0b0001,
+            /// 128 preamble symbols
+            Symbols128 = 0b0101,
+            /// 256 preamble symbols
+            Symbols256 = 0b1001,
+            /// 512 preamble symbols
+            Symbols512 = 0b1101,
+            /// 1024 preamble symbols
+            Symbols1024 = 0b0010,
+            /// 1536 preamble symbols
+            Symbols1536 = 0b0110,
+            /// 2048 preamble symbols
+            Symbols2048 = 0b1010,
+            /// 4096 preamble symbols
+            Symbols4096 = 0b0011,
+        };
         /// Transmit buffer index offset
         TXB_OFFSET, 16, 10,  u16;
         /// Fine PSR control
@@ -436,7 +1073,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x2C, 4, RW, DX_TIME(dx_time)] {
+    [0x00, 0x2C, 4, RW, DX_TIME(dx_time), 0x00] {
         ///
         /// Delayed Send or Receive Time
         ///
@@ -455,7 +1092,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x30, 4, RW, DREF_TIME(dref_time)] {
+    [0x00, 0x30, 4, RW, DREF_TIME(dref_time), 0x00] {
         ///
         /// Delayed send or receive reference time
         ///
@@ -468,12 +1105,12 @@ impl_registers! {
         VALUE, 0, 32,  u32;
     }
     /// Receive frame wait timeout period
-    [0x00, 0x34, 3, RW, RX_FWTO(rx_fwto)] {
+    [0x00, 0x34, 3, RW, RX_FWTO(rx_fwto), 0x00] {
         /// Receive frame wait timeout period
         VALUE, 0, 24,  u32;
     }
     /// System Control Register
-    [0x00, 0x38, 1, RW, SYS_CTRL(sys_ctrl)] {
+    [0x00, 0x38, 1, RW, SYS_CTRL(sys_ctrl), 0x00] {
         /// System control
         VALUE, 0, 8,  u8;
     }
@@ -484,83 +1121,83 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x3C, 6, RW, SYS_ENABLE(sys_enable)] {
+    [0x00, 0x3C, 6, RW, SYS_ENABLE(sys_enable), 0x00] {
         /// Mask clock PLL lock event
-        CPLOCK_EN, 1, 1,  u8;
+        CPLOCK_EN, 1, 1,  bool;
         /// Mask SPI CRC Error event
-        SPICRCE_EN, 2, 1,  u8;
+        SPICRCE_EN, 2, 1,  bool;
         /// Mask automatic acknowledge trigger event
-        AAT_EN, 3, 1,  u8;
+        AAT_EN, 3, 1,  bool;
         /// Mask transmit frame begins event
-        TXFRB_EN, 4, 1,  u8;
+        TXFRB_EN, 4, 1,  bool;
         /// Mask transmit preamble sent event
-        TXPRS_EN, 5, 1,  u8;
+        TXPRS_EN, 5, 1,  bool;
         /// Mask transmit PHY Header Sent event
-        TXPHS_EN, 6, 1,  u8;
+        TXPHS_EN, 6, 1,  bool;
         /// Mask transmit frame sent event
-        TXFRS_EN, 7, 1,  u8;
+        TXFRS_EN, 7, 1,  bool;
         /// Mask receiver preamble detected event
-        RXPRD_EN, 8, 1,  u8;
+        RXPRD_EN, 8, 1,  bool;
         /// Mask receiver SFD detected event
-        RXSFDD_EN, 9, 1,  u8;
+        RXSFDD_EN, 9, 1,  bool;
         /// Mask CIA processing done event
-        CIADONE_EN, 10, 1,  u8;
+        CIADONE_EN, 10, 1,  bool;
         /// Mask receiver PHY header detect event
-        RXPHD_EN, 11, 1,  u8;
+        RXPHD_EN, 11, 1,  bool;
         /// Mask receiver PHY header error event
-        RXPHE_EN, 12, 1,  u8;
+        RXPHE_EN, 12, 1,  bool;
         /// Mask receiver data frame ready event
-        RXFR_EN, 13, 1,  u8;
+        RXFR_EN, 13, 1,  bool;
         /// Mask receiver FCS good event
-        RXFCG_EN, 14, 1,  u8;
+        RXFCG_EN, 14, 1,  bool;
         /// Mask receiver FCS error event
-        RXFCE_EN, 15, 1,  u8;
+        RXFCE_EN, 15, 1,  bool;
         /// Mask receiver Reed Solomon Frame Sync Loss event
-        RXRFSL_EN, 16, 1,  u8;
+        RXRFSL_EN, 16, 1,  bool;
         /// Mask Receive Frame Wait Timeout event
-        RXFTO_EN, 17, 1,  u8;
+        RXFTO_EN, 17, 1,  bool;
         /// Mask leading edge detection processing error event
-        CIAERR_EN, 18, 1,  u8;
+        CIAERR_EN, 18, 1,  bool;
         /// Mask Voltage warning event
-        VWARN_EN, 19, 1,  u8;
+        VWARN_EN, 19, 1,  bool;
         /// Receiver overrun
-        RXOVRR_EN, 20, 1,  u8;
+        RXOVRR_EN, 20, 1,  bool;
         /// Mask Preamble detection timeout event
-        RXPTO_EN, 21, 1,  u8;
+        RXPTO_EN, 21, 1,  bool;
         /// Mask SPI ready event
-        SPIRDY_EN, 23, 1,  u8;
+        SPIRDY_EN, 23, 1,  bool;
         /// Mask IDLE RC event
-        RCINIT_EN, 24, 1,  u8;
+        RCINIT_EN, 24, 1,  bool;
         /// Mask PLL Losing Lock warning event
-        PLL_HILO_EN, 25, 1,  u8;
+        PLL_HILO_EN, 25, 1,  bool;
         /// Mask Receive SFD timeout event
-        RXSTO_EN, 26, 1,  u8;
+        RXSTO_EN, 26, 1,  bool;
         /// Mask Half Period Delay Warning event
-        HPDWARN_EN, 27, 1,  u8;
+        HPDWARN_EN, 27, 1,  bool;
         /// Mask Scramble Timestamp Sequence (STS) error event
-        CPERR_EN, 28, 1,  u8;
+        CPERR_EN, 28, 1,  bool;
         /// Mask Automatic Frame Filtering rejection event
-        ARFE_EN, 29, 1,  u8;
+        ARFE_EN, 29, 1,  bool;
         /// Mask Receiver Preamble Rejection event
-        RXPREJ_EN, 33, 1,  u8;
+        RXPREJ_EN, 33, 1,  bool;
         /// Mask Voltage/Temperature variation dtection interrupt event
-        VT_DET_EN, 36, 1,  u8;
+        VT_DET_EN, 36, 1,  bool;
         /// Mask GPIO interrupt event
-        GPIOIRQ_EN, 37, 1,  u8;
+        GPIOIRQ_EN, 37, 1,  bool;
         /// Mask AES done interrupt event
-        AES_DONE_EN, 38, 1,  u8;
+        AES_DONE_EN, 38, 1,  bool;
         /// Mask AES error interrupt event
-        AES_ERR_EN, 39, 1,  u8;
+        AES_ERR_EN, 39, 1,  bool;
         /// Mask CMD error interrupt event
-        CDM_ERR_EN, 40, 1,  u8;
+        CDM_ERR_EN, 40, 1,  bool;
         /// Mask SPI overflow interrupt event
-        SPI_OVF_EN, 41, 1,  u8;
+        SPI_OVF_EN, 41, 1,  bool;
         /// Mask SPI underflow interrupt event
-        SPI_UNF_EN, 42, 1,  u8;
+        SPI_UNF_EN, 42, 1,  bool;
         /// Mask SPI error interrupt event
-        SPI_ERR_EN, 43, 1,  u8;
+        SPI_ERR_EN, 43, 1,  bool;
         /// Mask CCA fail interrupt event
-        CCA_FAIL_EN, 44, 1,  u8;
+        CCA_FAIL_EN, 44, 1,  bool;
     }
     ///
     /// System Event Status Register
@@ -569,100 +1206,114 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x44, 6, RW, SYS_STATUS(sys_status)] {
+    [0x00, 0x44, 6, RW, SYS_STATUS(sys_status), ?] {
         /// Interrupt Request Status
-        IRQS, 0, 1,  u8;
+        IRQS, 0, 1,  bool;
         /// Clock PLL Lock
-        CPLOCK, 1, 1,  u8;
+        CPLOCK, 1, 1,  bool;
         /// External Sync Clock Reset
-        SPICRCE, 2, 1,  u8;
+        SPICRCE, 2, 1,  bool;
         /// Automatic Acknowledge Trigger
-        AAT, 3, 1,  u8;
+        AAT, 3, 1,  bool;
         /// TX Frame Begins
-        TXFRB, 4, 1,  u8;
+        TXFRB, 4, 1,  bool;
         /// TX Preamble Sent
-        TXPRS, 5, 1,  u8;
+        TXPRS, 5, 1,  bool;
         /// TX PHY Header Sent
-        TXPHS, 6, 1,  u8;
+        TXPHS, 6, 1,  bool;
         /// TX Frame Sent
-        TXFRS, 7, 1,  u8;
+        TXFRS, 7, 1,  bool;
         /// RX Preamble Detected
-        RXPRD, 8, 1,  u8;
+        RXPRD, 8, 1,  bool;
         /// RX SFD Detected
-        RXSFDD, 9, 1,  u8;
+        RXSFDD, 9, 1,  bool;
         /// LDE Processing Done
-        CIADONE, 10, 1,  u8;
+        CIADONE, 10, 1,  bool;
         /// RX PHY Header Detect
-        RXPHD, 11, 1,  u8;
+        RXPHD, 11, 1,  bool;
         /// RX PHY Header Error
-        RXPHE, 12, 1,  u8;
+        RXPHE, 12, 1,  bool;
         /// RX Data Frame Ready
-        RXFR, 13, 1,  u8;
+        RXFR, 13, 1,  bool;
         /// RX FCS Good
-        RXFCG, 14, 1,  u8;
+        RXFCG, 14, 1,  bool;
         /// RX FCS Error
-        RXFCE, 15, 1,  u8;
+        RXFCE, 15, 1,  bool;
         /// RX Reed-Solomon Frame Sync Loss
-        RXFSL, 16, 1,  u8;
+        RXFSL, 16, 1,  bool;
         /// RX Frame Wait Timeout
-        RXFTO, 17, 1,  u8;
+        RXFTO, 17, 1,  bool;
         /// Leading Edge Detection Error
-        CIAERR, 18, 1,  u8;
+        CIAERR, 18, 1,  bool;
         /// Low voltage warning
-        VWARN, 19, 1,  u8;
+        VWARN, 19, 1,  bool;
         /// RX Overrun
-        RXOVRR, 20, 1,  u8;
+        RXOVRR, 20, 1,  bool;
         /// Preamble detection timeout
-        RXPTO, 21, 1,  u8;
+        RXPTO, 21, 1,  bool;
         /// SPI ready for host access
-        SPIRDY, 23, 1,  u8;
+        SPIRDY, 23, 1,  bool;
         /// RC INIT
-        RCINIT, 24, 1,  u8;
+        RCINIT, 24, 1,  bool;
         /// lock PLL Losing Lock
-        PLL_HILO, 25, 1,  u8;
+        PLL_HILO, 25, 1,  bool;
         /// Receive SFD timeout
-        RXSTO, 26, 1,  u8;
+        RXSTO, 26, 1,  bool;
         /// Half Period Delay Warning
-        HPDWARN, 27, 1,  u8;
+        HPDWARN, 27, 1,  bool;
         /// Scramble Timestamp Sequence (STS) error
-        CPERR, 28, 1,  u8;
+        CPERR, 28, 1,  bool;
         /// Automatic Frame Filtering rejection
-        ARFE, 29, 1,  u8;
+        ARFE, 29, 1,  bool;
         /// Receiver Preamble Rejection
-        RXPREJ, 29, 1,  u8;
+        RXPREJ, 33, 1,  bool;
         /// Voltage or temperature variation detected
-        VT_DET, 33, 1,  u8;
+        VT_DET, 36, 1,  bool;
         /// GPIO interrupt
-        GPIOIRQ, 36, 1,  u8;
+        GPIOIRQ, 37, 1,  bool;
         /// AES-DMA operation complete
-        AES_DONE, 37, 1,  u8;
+        AES_DONE, 38, 1,  bool;
         /// AES-DMA error
-        AES_ERR, 38, 1,  u8;
+        AES_ERR, 39, 1,  bool;
         /// Command error
-        CMD_ERR, 39, 1,  u8;
+        CMD_ERR, 40, 1,  bool;
         /// SPI overflow error
-        SPI_OVF, 40, 1,  u8;
+        SPI_OVF, 41, 1,  bool;
         /// SPI underflow error
-        SPI_UNF, 41, 1,  u8;
+        SPI_UNF, 42, 1,  bool;
         /// SPI collision error
-        SPIERR, 42, 1,  u8;
+        SPIERR, 43, 1,  bool;
         /// This event will be set as a result of failure of CMD_CCA_TX to transmit a packet
-        CCA_FAIL, 43, 1,  u8;
+        CCA_FAIL, 44, 1,  bool;
     }
     /// RX Frame Information
-    [0x00, 0x4C, 4, RO, RX_FINFO(rx_finfo)] {
+    [0x00, 0x4C, 4, RO, RX_FINFO(rx_finfo), 0x00] {
         /// Receive Frame Length
         RXFLEN, 0, 10,  u16;
         /// Receive Non-Standard Preamble Length
         RXNSPL, 11, 2,  u8;
         /// Receive Bit Rate Report
-        RXBR, 13, 1,  u8;
+        RXBR, 13, 1,  DataRate;
         /// Receiver Ranging
-        RNG, 15, 1,  u8;
+        RNG, 15, 1,  bool;
         /// RX Pulse Repetition Rate Report
-        RXPRF, 16, 2,  u8;
+        RXPRF, 16, 2,  PulseRepetitionFrequency(u8) {
+            /// 16 MHz pulse repetition frequency
+            Prf16M = 0b01,
+            /// 64 MHz pulse repetition frequency
+            Prf64M = 0b10,
+        };
         /// RX Preamble Repetition
-        RXPSR, 18, 2,  u8;
+        RXPSR, 18, 2,  RxPreambleLength(u8) {
+            /// 16 preamble symbols
+            Symbols16 = 0b00,
+            /// 64 preamble symbols
+            Symbols64 = 0b01,
+            /// 1024 preamble symbols
+            Symbols1024 = 0b10,
+            /// 4096 preamble symbols
+            Symbols4096 = 0b11,
+        };
         /// Preamble Accumulation Count
         RXPACC, 20, 12,  u16;
     }
@@ -673,7 +1324,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x64, 16, RO, RX_TIME(rx_time)] {
+    [0x00, 0x64, 16, RO, RX_TIME(rx_time), 0x00] {
         ///
         /// Fully adjusted timestamp of reception
         ///
@@ -698,7 +1349,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x00, 0x74, 5, RO, TX_TIME(tx_time)] {
+    [0x00, 0x74, 5, RO, TX_TIME(tx_time), 0x00] {
         ///
         /// Fully adjusted time of transmission
         ///
@@ -714,7 +1365,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x01, 0x00, 4, RO, TX_RAWST(tx_rawst)] {
+    [0x01, 0x00, 4, RO, TX_RAWST(tx_rawst), 0x00] {
         ///
         /// Raw timestamp of transmission
         ///
@@ -727,7 +1378,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x01, 0x04, 2, RW, TX_ANTD(tx_antd)] {
+    [0x01, 0x04, 2, RW, TX_ANTD(tx_antd), 0x4015] {
         ///
         /// Transmitter antenna delay
         ///
@@ -747,7 +1398,7 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x01, 0x08, 4, RW, ACK_RESP(ack_resp)] {
+    [0x01, 0x08, 4, RW, ACK_RESP(ack_resp), ?] {
         ///
         /// Wait-for-Response turn-around time
         ///
@@ -778,7 +1429,7 @@ impl_registers! {
         ACK_TIM, 24, 8,  u8;
     }
     /// TX Power Control
-    [0x01, 0x0C, 4, RW, TX_POWER(tx_power)] {
+    [0x01, 0x0C, 4, RW, TX_POWER(tx_power), 0xFEFE_FEFE] {
         /// TX Power Control value
         VALUE, 0, 32,  u32;
     }
@@ -789,105 +1440,153 @@ impl_registers! {
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x01, 0x14, 2, RW, CHAN_CTRL(chan_ctrl)] {
+    [0x01, 0x14, 2, RW, CHAN_CTRL(chan_ctrl), ?] {
         /// Selects the receive channel.
-        RF_CHAN, 0, 1,  u8;
+        RF_CHAN, 0, 1,  Channel(u8) {
+            /// UWB channel 5 (6489.6 MHz)
+            Channel5 = 0b0,
+            /// UWB channel 9 (7987.2 MHz)
+            Channel9 = 0b1,
+        };
         /// Enables the non-standard Decawave proprietary SFD sequence.
-        SFD_TYPE, 1, 2,  u8;
+        SFD_TYPE, 1, 2,  SfdType(u8) {
+            /// The short 8 symbol SFD defined in IEEE 802.15.4a.
+            Ieee4a = 0b00,
+            /// The Decawave proprietary 8 symbol SFD.
+            Decawave8 = 0b01,
+            /// The Decawave proprietary 16 symbol SFD.
+            Decawave16 = 0b10,
+            /// The 8 symbol SFD defined in IEEE 802.15.4z.
+            Ieee4z = 0b11,
+        };
         /// Selects the preamble code used in the transmitter.
         TX_PCODE, 3, 5,  u8;
         /// Selects the preamble code used in the receiver.
         RX_PCODE, 8, 5,  u8;
     }
     /// Low Energy device address 0 and 1
-    [0x01, 0x18, 4, RW, LE_PEND_01(le_pend_01)] {
+    [0x01, 0x18, 4, RW, LE_PEND_01(le_pend_01), 0x00] {
         /// Low Energy device 16-bit address
         LE_ADDR0, 0, 16,  u16;
         /// Low Energy device 16-bit address
         LE_ADDR1, 16, 16,  u16;
     }
     /// Low Energy device address 2 and 3
-    [0x01, 0x1C, 4, RW, LE_PEND_23(le_pend_23)] {
+    [0x01, 0x1C, 4, RW, LE_PEND_23(le_pend_23), 0x00] {
         /// Low Energy device 16-bit address
         LE_ADDR2, 0, 16,  u16;
         /// Low Energy device 16-bit address
         LE_ADDR3, 16, 16,  u16;
     }
     /// SPI collision status
-    [0x01, 0x20, 1, RW, SPI_COLLISION(spi_collision)] {
+    [0x01, 0x20, 1, RW, SPI_COLLISION(spi_collision), 0x00] {
         /// SPI collision status
         VALUE, 0, 8,  u8;
     }
     /// RX double buffer status
-    [0x01, 0x24, 1, RW, RDB_STATUS(rdb_status)] {
+    [0x01, 0x24, 1, RW, RDB_STATUS(rdb_status), 0x00] {
         /// Receiver FCS Good
-        RXFCG0, 0, 1,  u8;
+        RXFCG0, 0, 1,  bool;
         /// Receiver Data Frame Ready
-        RXFR0, 1, 1,  u8;
+        RXFR0, 1, 1,  bool;
         /// CIA processing done on the CIR relating to a message in RX_BUFFER_0 when operating in double buffer mode
-        CIADONE0, 2, 1,  u8;
+        CIADONE0, 2, 1,  bool;
         /// Scramble Timestamp Sequence (STS) error
-        CP_ERR0, 3, 1,  u8;
+        CP_ERR0, 3, 1,  bool;
         /// Receiver FCS Good
-        RXFCG1, 4, 1,  u8;
+        RXFCG1, 4, 1,  bool;
         /// Receiver Data Frame Ready
-        RXFR1, 5, 1,  u8;
+        RXFR1, 5, 1,  bool;
         /// CIA processing done on the CIR relating to a message in RX_BUFFER_1 when operating in double buffer mode
-        CIADONE1, 6, 1,  u8;
+        CIADONE1, 6, 1,  bool;
         /// Scramble Timestamp Sequence (STS) error
-        CP_ERR1, 7, 1,  u8;
+        CP_ERR1, 7, 1,  bool;
     }
     /// RX double buffer diagnostic configuration
-    [0x01, 0x28, 1, RW, RDB_DIAG(rdb_diag)] {
+    [0x01, 0x28, 1, RW, RDB_DIAG(rdb_diag), ?] {
         /// RX double buffer diagnostic mode
         RDB_DMODE, 0, 3,  u8;
     }
     /// AES configuration
-    [0x01, 0x30, 2, RW, AES_CFG(aes_cfg)] {
+    [0x01, 0x30, 2, RW, AES_CFG(aes_cfg), ?] {
         /// Mode of operation of AES core
-        MODE, 0, 1,  u8;
+        MODE, 0, 1,  AesMode(u8) {
+            /// Encrypt the data.
+            Encrypt = 0b0,
+            /// Decrypt the data.
+            Decrypt = 0b1,
+        };
         /// AES Key Size
-        KEY_SIZE, 1, 2,  u8;
+        KEY_SIZE, 1, 2,  AesKeySize(u8) {
+            /// 128-bit key
+            Bits128 = 0b00,
+            /// 192-bit key
+            Bits192 = 0b01,
+            /// 256-bit key
+            Bits256 = 0b10,
+        };
         /// Address offset of AES KEY
         KEY_ADDR, 3, 3,  u8;
         /// Load the AES KEY from AES KEY source
-        KEY_LOAD, 6, 1,  u8;
+        KEY_LOAD, 6, 1,  bool;
         /// AES key source
-        KEY_SRC, 7, 1,  u8;
+        KEY_SRC, 7, 1,  bool;
         /// Size of AES tag field
-        TAG_SIZE, 8, 3,  u8;
+        TAG_SIZE, 8, 3,  AesTagSize(u8) {
+            /// No tag
+            Bytes0 = 0b000,
+            /// 4 octet tag
+            Bytes4 = 0b001,
+            /// 6 octet tag
+            Bytes6 = 0b010,
+            /// 8 octet tag
+            Bytes8 = 0b011,
+            /// 10 octet tag
+            Bytes10 = 0b100,
+            /// 12 octet tag
+            Bytes12 = 0b101,
+            /// 14 octet tag
+            Bytes14 = 0b110,
+            /// 16 octet tag
+            Bytes16 = 0b111,
+        };
         /// AES Core select
-        CORE_SEL, 11, 1,  u8;
+        CORE_SEL, 11, 1,  AesCore(u8) {
+            /// The GCM core
+            Gcm = 0b0,
+            /// The CCM* core
+            Ccm = 0b1,
+        };
         /// AES key Memory source
-        KEY_OTP, 12, 1,  u8;
+        KEY_OTP, 12, 1,  bool;
     }
     /// AES GCM core mode
-    [0x01, 0x34, 4, RW, AES_IV0(aes_iv0)] {
+    [0x01, 0x34, 4, RW, AES_IV0(aes_iv0), 0x00] {
         /// AES GCM core mode
         VALUE, 0, 32,  u32;
     }
     /// AES GCM core mode
-    [0x01, 0x38, 4, RW, AES_IV1(aes_iv1)] {
+    [0x01, 0x38, 4, RW, AES_IV1(aes_iv1), 0x00] {
         /// AES GCM core mode
         VALUE, 0, 32,  u32;
     }
     /// AES GCM core mode
-    [0x01, 0x3C, 4, RW, AES_IV2(aes_iv2)] {
+    [0x01, 0x3C, 4, RW, AES_IV2(aes_iv2), 0x00] {
         /// AES GCM core mode
         VALUE, 0, 32,  u32;
     }
     /// AES GCM core mode
-    [0x01, 0x40, 2, RW, AES_IV3(aes_iv3)] {
+    [0x01, 0x40, 2, RW, AES_IV3(aes_iv3), 0x00] {
         /// AES GCM core mode
         VALUE, 0, 16,  u16;
     }
     /// AES GCM core mode
-    [0x01, 0x42, 2, RW, AES_IV4(aes_iv4)] {
+    [0x01, 0x42, 2, RW, AES_IV4(aes_iv4), 0x00] {
         /// AES GCM core mode
         VALUE, 0, 16,  u16;
     }
     /// DMA configuration register
-    [0x01, 0x44, 8, RW, DMA_CFG(dma_cfg)] {
+    [0x01, 0x44, 8, RW, DMA_CFG(dma_cfg), 0x00] {
         /// Source memory port for DMA transfer
         SRC_PORT, 0, 3,  u8;
         /// Address offset within source memory for DMA transfer
@@ -897,130 +1596,140 @@ impl_registers! {
         /// Address offset within destination memory for DMA transfer
         DST_ADDR, 16, 10,  u16;
         /// Select the endianess of the CP seed port
-        CP_END_SEL, 26, 1,  u8;
+        CP_END_SEL, 26, 1,  bool;
         /// Size of header field in the packet to be transferred via the DMA
         HDR_SIZE, 32, 7,  u8;
         /// Size of payload field in the packet to be transferred via the DMA
-        PYLD_SIZE, 39, 10,  u8;
+        PYLD_SIZE, 39, 10,  u16;
     }
     /// Start AES operation
-    [0x01, 0x4C, 1, RW, AES_START(aes_start)] {
+    [0x01, 0x4C, 1, RW, AES_START(aes_start), 0x00] {
         /// Start AES operation
-        VALUE, 0, 1,  u8;
+        VALUE, 0, 1,  bool;
     }
     /// The AES Status
-    [0x01, 0x50, 4, RW, AES_STS(aes_sts)] {
+    [0x01, 0x50, 4, RW, AES_STS(aes_sts), ?] {
         /// AES operation complete. Write 1 to clear
-        AES_DONE, 0, 1,  u8;
+        AES_DONE, 0, 1,  bool;
         /// AES authentication error. Write 1 to clear.
-        AUTH_ERR, 1, 1,  u8;
+        AUTH_ERR, 1, 1,  bool;
         /// Indicates error with DMA transfer to memory. Write 1 to clear
-        TRANS_ERR, 2, 1,  u8;
+        TRANS_ERR, 2, 1,  bool;
         /// Indicates access conflict between multiple masters (SPI host, CIA engine and AES-DMA engine) trying to access same memory
-        MEM_CONF, 3, 1,  u8;
+        MEM_CONF, 3, 1,  bool;
         /// Indicates AES scratch RAM is empty
-        RAM_EMPTY, 4, 1,  u8;
+        RAM_EMPTY, 4, 1,  bool;
         /// Indicates AES scratch RAM is full
-        RAM_FULL, 5, 1,  u8;
+        RAM_FULL, 5, 1,  bool;
     }
     /// The 128-bit KEY for the AES GCM/CCM* core
-    [0x01, 0x54, 16, RW, AES_KEY(aes_key)] {
+    [0x01, 0x54, 16, RW, AES_KEY(aes_key), ?] {
         /// value
         VALUE, 0, 128,  u128;
     }
     /// STS configuration
-    [0x02, 0x00, 2, RW, STS_CFG(sts_cfg)] {
+    [0x02, 0x00, 2, RW, STS_CFG(sts_cfg), 0x000F] {
         /// STS length
         CPS_LEN, 0, 8,  u8;
     }
     /// STS control
-    [0x02, 0x04, 1, RW, STS_CTRL(sts_ctrl)] {
+    [0x02, 0x04, 1, RW, STS_CTRL(sts_ctrl), 0x00] {
         /// Load STS_IV bit into the AES-128 block for the generation of STS
-        LOAD_IV, 0, 1,  u8;
+        LOAD_IV, 0, 1,  bool;
         /// Start from last, when it is set to 1 the STS generation starts from the last count that was used by the AES-128 block for the generation of the previous STS.
-        RST_LAST, 1, 1,  u8;
+        RST_LAST, 1, 1,  bool;
     }
     /// STS status
-    [0x02, 0x08, 2, RW, STS_STS(sts_sts)] {
+    [0x02, 0x08, 2, RW, STS_STS(sts_sts), ?] {
         /// STS accumulation quality
         ACC_QUAL, 0, 12,  u16;
     }
     /// STS 128-bit KEY
-    [0x02, 0x0C, 16, RW, STS_KEY(sts_key)] {
+    [0x02, 0x0C, 16, RW, STS_KEY(sts_key), 0x1414_8674_D1D3_36AA_F860_50A8_14EB_220F] {
         /// value
         VALUE, 0, 128,  u128;
     }
     /// STS 128-bit IV
-    [0x02, 0x1C, 16, RW, STS_IV(sts_iv)] {
+    [0x02, 0x1C, 16, RW, STS_IV(sts_iv), 0x362E_EB34_C44F_A8FB_D37E_C3CA_1F9A_3DE4] {
         /// value
         VALUE, 0, 128,  u128;
     }
     /// RX tuning configuration register
-    [0x03, 0x18, 2, RW, DGC_CFG(dgc_cfg)] {
+    [0x03, 0x18, 2, RW, DGC_CFG(dgc_cfg), ?] {
         /// RX tuning enable bit
-        RX_TUNE_EN, 0, 1,  u8;
+        RX_TUNE_EN, 0, 1,  bool;
         /// RX tuning threshold configuration for 64 MHz PRF
         THR_64, 9, 6,  u8;
     }
     /// DGC_CFG0
-    [0x03, 0x1C, 4, RW, DGC_CFG0(dgc_cfg0)] {
+    [0x03, 0x1C, 4, RW, DGC_CFG0(dgc_cfg0), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_CFG1
-    [0x03, 0x20, 4, RW, DGC_CFG1(dgc_cfg1)] {
+    [0x03, 0x20, 4, RW, DGC_CFG1(dgc_cfg1), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_0
-    [0x03, 0x38, 4, RW, DGC_LUT_0(dgc_lut_0)] {
+    [0x03, 0x38, 4, RW, DGC_LUT_0(dgc_lut_0), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_1
-    [0x03, 0x3C, 4, RW, DGC_LUT_1(dgc_lut_1)] {
+    [0x03, 0x3C, 4, RW, DGC_LUT_1(dgc_lut_1), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_2
-    [0x03, 0x40, 4, RW, DGC_LUT_2(dgc_lut_2)] {
+    [0x03, 0x40, 4, RW, DGC_LUT_2(dgc_lut_2), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_3
-    [0x03, 0x44, 4, RW, DGC_LUT_3(dgc_lut_3)] {
+    [0x03, 0x44, 4, RW, DGC_LUT_3(dgc_lut_3), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_4
-    [0x03, 0x48, 4, RW, DGC_LUT_4(dgc_lut_4)] {
+    [0x03, 0x48, 4, RW, DGC_LUT_4(dgc_lut_4), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_5
-    [0x03, 0x4C, 4, RW, DGC_LUT_5(dgc_lut_5)] {
+    [0x03, 0x4C, 4, RW, DGC_LUT_5(dgc_lut_5), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// DGC_LUT_6
-    [0x03, 0x50, 4, RW, DGC_LUT_6(dgc_lut_6)] {
+    [0x03, 0x50, 4, RW, DGC_LUT_6(dgc_lut_6), ?] {
         /// Value
         VALUE, 0, 32,  u32;
     }
     /// Reports DGC information
-    [0x03, 0x60, 4, RW, DGC_DBG(dgc_dbg)] {
+    [0x03, 0x60, 4, RW, DGC_DBG(dgc_dbg), ?] {
         /// DGC decision index.
         DGC_DECISION, 28, 3,  u8;
     }
     /// External clock synchronisation counter configuration
-    [0x04, 0x00, 4, RW, EC_CTRL(ec_ctrl)] {
+    [0x04, 0x00, 4, RW, EC_CTRL(ec_ctrl), ?] {
         /// Wait counter used for external timebase reset
         OSTS_WAIT, 3, 8,  u8;
         /// External timebase reset mode enable bit
-        OSTR_MODE, 11, 1,  u8;
+        OSTR_MODE, 11, 1,  bool;
+    }
+    /// External clock synchronisation counter captured on RMARKER
+    [0x04, 0x04, 4, RO, EC_RXTC(ec_rxtc), 0x0000_0000] {
+        /// External clock synchronisation counter captured on RMARKER
+        RX_TS_EST, 0, 32,  u32;
+    }
+    /// External clock offset to first path 1 GHz counter
+    [0x04, 0x08, 4, RO, EC_GOLP(ec_golp), 0x0000_0000] {
+        /// The offset of the external clock from the first path, in units of the 1 GHz counter
+        OFFSET_EXT, 0, 6,  u8;
     }
     /// RX calibration block configuration
-    [0x04, 0x0C, 4, RW, RX_CAL(rx_cal)] {
+    [0x04, 0x0C, 4, RW, RX_CAL(rx_cal), ?] {
         /// RX calibration mode
         CAL_MODE, 0, 2,  u8;
         /// RX calibration enable
@@ -1029,561 +1738,629 @@ impl_registers! {
         COMP_DLY, 16, 4,  u8;
     }
     /// RX calibration block result
-    [0x04, 0x14, 4, RW, RX_CAL_RESI(rx_cal_resi)] {
+    [0x04, 0x14, 4, RW, RX_CAL_RESI(rx_cal_resi), ?] {
         /// reports the result once the RX calibration is complete
         VALUE, 0, 29,  u32;
     }
     /// RX calibration block result
-    [0x04, 0x1C, 4, RW, RX_CAL_RESQ(rx_cal_resq)] {
+    [0x04, 0x1C, 4, RW, RX_CAL_RESQ(rx_cal_resq), ?] {
         /// reports the result once the RX calibration is complete
         VALUE, 0, 29,  u32;
     }
     /// RX calibration block status
-    [0x04, 0x20, 1, RW, RX_CAL_STS(rx_cal_sts)] {
+    [0x04, 0x20, 1, RW, RX_CAL_STS(rx_cal_sts), 0x00] {
         ///  reports the status once the RX calibration is complete
-        VALUE, 0, 1,  u8;
+        VALUE, 0, 1,  bool;
     }
     /// GPIO Mode Control Register
-    [0x05, 0x00, 4, RW, GPIO_MODE(gpio_mode)] {
+    [0x05, 0x00, 4, RW, GPIO_MODE(gpio_mode), 0x00] {
         ///  Mode Selection for GPIO0/RXOKLED
-        MSGP0, 0, 3,  u8;
+        MSGP0, 0, 3,  Gpio0Mode(u8) {
+            /// GPIO0, a general purpose input or output.
+            Gpio = 0b000,
+            /// RXOKLED, the receive OK LED output, asserted when a frame with a good CRC is received.
+            RxOkLed = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO1/SFDLED
-        MSGP1, 3, 3,  u8;
+        MSGP1, 3, 3,  Gpio1Mode(u8) {
+            /// GPIO1, a general purpose input or output.
+            Gpio = 0b000,
+            /// SFDLED, the SFD LED output, asserted when an SFD is detected.
+            SfdLed = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO2/RXLED
-        MSGP2, 6, 3,  u8;
+        MSGP2, 6, 3,  Gpio2Mode(u8) {
+            /// GPIO2, a general purpose input or output.
+            Gpio = 0b000,
+            /// RXLED, the receive LED output, asserted while the receiver is enabled.
+            RxLed = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO3/TXLED
-        MSGP3, 9, 3,  u8;
+        MSGP3, 9, 3,  Gpio3Mode(u8) {
+            /// GPIO3, a general purpose input or output.
+            Gpio = 0b000,
+            /// TXLED, the transmit LED output, asserted after a frame has been transmitted.
+            TxLed = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO4/EXTPA
-        MSGP4, 12, 3,  u8;
+        MSGP4, 12, 3,  Gpio4Mode(u8) {
+            /// GPIO4, a general purpose input or output.
+            Gpio = 0b000,
+            /// EXTPA, the external power amplifier enable output.
+            ExtPa = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO5/EXTTXE
-        MSGP5, 15, 3,  u8;
+        MSGP5, 15, 3,  Gpio5Mode(u8) {
+            /// GPIO5, a general purpose input or output.
+            Gpio = 0b000,
+            /// EXTTXE, the external transmit enable output, asserted while the transmitter is active.
+            ExtTxe = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
         ///  Mode Selection for GPIO6/EXTRXE
-        MSGP6, 18, 3,  u8;
-        ///  Mode Selection for GPIO7
-        MSGP7, 21, 3,  u8;
-        ///  Mode Selection for GPIO8
-        MSGP8, 24, 3,  u8;
+        MSGP6, 18, 3,  Gpio6Mode(u8) {
+            /// GPIO6, a general purpose input or output.
+            Gpio = 0b000,
+            /// EXTRXE, the external receive enable output, asserted while the receiver is active.
+            ExtRxe = 0b001,
+            /// A control line of the external antenna switch, driven by the device for PDoA.
+            PdoaSwitch = 0b010,
+        };
+        ///  Mode Selection for SYNC/GPIO7
+        MSGP7, 21, 3,  Gpio7Mode(u8) {
+            /// SYNC, the input used to synchronise the system time of the device with an external clock.
+            Sync = 0b000,
+            /// GPIO7, a general purpose input or output.
+            Gpio = 0b001,
+        };
+        ///  Mode Selection for IRQ/GPIO8
+        MSGP8, 24, 3,  Gpio8Mode(u8) {
+            /// IRQ, the interrupt request output of the device.
+            Irq = 0b000,
+            /// GPIO8, a general purpose input or output.
+            Gpio = 0b001,
+        };
     }
     /// GPIO Drive Strength and Pull Control
-    [0x05, 0x04, 2, RW, GPIO_PULL_EN(gpio_pull_en)] {
+    [0x05, 0x04, 2, RW, GPIO_PULL_EN(gpio_pull_en), 0x01FF] {
         ///  Setting to 0 will lower the drive strength
-        MGPEN0, 0, 1,  u8;
+        MGPEN0, 0, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN1, 1, 1,  u8;
+        MGPEN1, 1, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN2, 2, 1,  u8;
+        MGPEN2, 2, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN3, 3, 1,  u8;
+        MGPEN3, 3, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN4, 4, 1,  u8;
+        MGPEN4, 4, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN5, 5, 1,  u8;
+        MGPEN5, 5, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN6, 6, 1,  u8;
+        MGPEN6, 6, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN7, 7, 1,  u8;
+        MGPEN7, 7, 1,  bool;
         ///  Setting to 0 will lower the drive strength
-        MGPEN8, 8, 1,  u8;
+        MGPEN8, 8, 1,  bool;
     }
     /// GPIO Direction Control Register
-    [0x05, 0x08, 2, RW, GPIO_DIR(gpio_dir)] {
+    [0x05, 0x08, 2, RW, GPIO_DIR(gpio_dir), 0x01FF] {
         ///   value of 0 means the pin is an output
-        GPD0, 0, 1,  u8;
+        GPD0, 0, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD1, 1, 1,  u8;
+        GPD1, 1, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD2, 2, 1,  u8;
+        GPD2, 2, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD3, 3, 1,  u8;
+        GPD3, 3, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD4, 4, 1,  u8;
+        GPD4, 4, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD5, 5, 1,  u8;
+        GPD5, 5, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD6, 6, 1,  u8;
+        GPD6, 6, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD7, 7, 1,  u8;
+        GPD7, 7, 1,  bool;
         ///   value of 0 means the pin is an output
-        GPD8, 8, 1,  u8;
+        GPD8, 8, 1,  bool;
     }
     /// GPIO Data Output Register
-    [0x05, 0x0C, 2, RW, GPIO_OUT(gpio_out)] {
+    [0x05, 0x0C, 2, RW, GPIO_OUT(gpio_out), 0x00] {
         ///   show the current output setting
-        GOP0, 0, 1,  u8;
+        GOP0, 0, 1,  bool;
         ///   show the current output setting
-        GOP1, 1, 1,  u8;
+        GOP1, 1, 1,  bool;
         ///   show the current output setting
-        GOP2, 2, 1,  u8;
+        GOP2, 2, 1,  bool;
         ///   show the current output setting
-        GOP3, 3, 1,  u8;
+        GOP3, 3, 1,  bool;
         ///   show the current output setting
-        GOP4, 4, 1,  u8;
+        GOP4, 4, 1,  bool;
         ///   show the current output setting
-        GOP5, 5, 1,  u8;
+        GOP5, 5, 1,  bool;
         ///   show the current output setting
-        GOP6, 6, 1,  u8;
+        GOP6, 6, 1,  bool;
         ///   show the current output setting
-        GOP7, 7, 1,  u8;
+        GOP7, 7, 1,  bool;
         ///   show the current output setting
-        GOP8, 8, 1,  u8;
+        GOP8, 8, 1,  bool;
     }
     /// GPIO Interrupt Enable
-    [0x05, 0x10, 2, RW, GPIO_IRQE(gpio_irqe)] {
+    [0x05, 0x10, 2, RW, GPIO_IRQE(gpio_irqe), 0x00] {
         ///   selected as interrupt source
-        GIRQE0, 0, 1,  u8;
+        GIRQE0, 0, 1,  bool;
         ///   selected as interrupt source
-        GIRQE1, 1, 1,  u8;
+        GIRQE1, 1, 1,  bool;
         ///   selected as interrupt source
-        GIRQE2, 2, 1,  u8;
+        GIRQE2, 2, 1,  bool;
         ///   selected as interrupt source
-        GIRQE3, 3, 1,  u8;
+        GIRQE3, 3, 1,  bool;
         ///   selected as interrupt source
-        GIRQE4, 4, 1,  u8;
+        GIRQE4, 4, 1,  bool;
         ///   selected as interrupt source
-        GIRQE5, 5, 1,  u8;
+        GIRQE5, 5, 1,  bool;
         ///   selected as interrupt source
-        GIRQE6, 6, 1,  u8;
+        GIRQE6, 6, 1,  bool;
         ///   selected as interrupt source
-        GIRQE7, 7, 1,  u8;
+        GIRQE7, 7, 1,  bool;
         ///   selected as interrupt source
-        GIRQE8, 8, 1,  u8;
+        GIRQE8, 8, 1,  bool;
     }
     /// GPIO Interrupt Status
-    [0x05, 0x14, 2, RW, GPIO_ISTS(gpio_ists)] {
+    [0x05, 0x14, 2, RW, GPIO_ISTS(gpio_ists), 0x00] {
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS0, 0, 1,  u8;
+        GISTS0, 0, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS1, 1, 1,  u8;
+        GISTS1, 1, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS2, 2, 1,  u8;
+        GISTS2, 2, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS3, 3, 1,  u8;
+        GISTS3, 3, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS4, 4, 1,  u8;
+        GISTS4, 4, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS5, 5, 1,  u8;
+        GISTS5, 5, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS6, 6, 1,  u8;
+        GISTS6, 6, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS7, 7, 1,  u8;
+        GISTS7, 7, 1,  bool;
         ///   Value 1 means GPIO gave rise to the GPIOIRQ SYS_STATUS event
-        GISTS8, 8, 1,  u8;
+        GISTS8, 8, 1,  bool;
     }
     /// GPIO Interrupt Sense Selection
-    [0x05, 0x18, 2, RW, GPIO_ISEN(gpio_isen)] {
+    [0x05, 0x18, 2, RW, GPIO_ISEN(gpio_isen), 0x00] {
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN0, 0, 1,  u8;
+        GISEN0, 0, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN1, 1, 1,  u8;
+        GISEN1, 1, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN2, 2, 1,  u8;
+        GISEN2, 2, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN3, 3, 1,  u8;
+        GISEN3, 3, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN4, 4, 1,  u8;
+        GISEN4, 4, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN5, 5, 1,  u8;
+        GISEN5, 5, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN6, 6, 1,  u8;
+        GISEN6, 6, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN7, 7, 1,  u8;
+        GISEN7, 7, 1,  bool;
         ///   GPIO IRQ Sense selection GPIO input
-        GISEN8, 8, 1,  u8;
+        GISEN8, 8, 1,  bool;
     }
     /// GPIO Interrupt Mode (Level / Edge)
-    [0x05, 0x1C, 2, RW, GPIO_IMODE(gpio_imode)] {
+    [0x05, 0x1C, 2, RW, GPIO_IMODE(gpio_imode), 0x00] {
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD0, 0, 1,  u8;
+        GIMOD0, 0, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD1, 1, 1,  u8;
+        GIMOD1, 1, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD2, 2, 1,  u8;
+        GIMOD2, 2, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD3, 3, 1,  u8;
+        GIMOD3, 3, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD4, 4, 1,  u8;
+        GIMOD4, 4, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD5, 5, 1,  u8;
+        GIMOD5, 5, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD6, 6, 1,  u8;
+        GIMOD6, 6, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD7, 7, 1,  u8;
+        GIMOD7, 7, 1,  bool;
         ///   GPIO IRQ Mode selection for GPIO input
-        GIMOD8, 8, 1,  u8;
+        GIMOD8, 8, 1,  bool;
     }
     /// GPIO Interrupt “Both Edge” Select
-    [0x05, 0x20, 2, RW, GPIO_IBES(gpio_ibes)] {
+    [0x05, 0x20, 2, RW, GPIO_IBES(gpio_ibes), 0x00] {
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES0, 0, 1,  u8;
+        GIBES0, 0, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES1, 1, 1,  u8;
+        GIBES1, 1, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES2, 2, 1,  u8;
+        GIBES2, 2, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES3, 3, 1,  u8;
+        GIBES3, 3, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES4, 4, 1,  u8;
+        GIBES4, 4, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES5, 5, 1,  u8;
+        GIBES5, 5, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES6, 6, 1,  u8;
+        GIBES6, 6, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES7, 7, 1,  u8;
+        GIBES7, 7, 1,  bool;
         ///   GPIO IRQ “Both Edge” selection for GPIO input
-        GIBES8, 8, 1,  u8;
+        GIBES8, 8, 1,  bool;
     }
     /// GPIO Interrupt Latch Clear
-    [0x05, 0x24, 4, RW, GPIO_ICLR(gpio_iclr)] {
+    [0x05, 0x24, 4, RW, GPIO_ICLR(gpio_iclr), 0x00] {
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR0, 0, 1,  u8;
+        GICLR0, 0, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR1, 1, 1,  u8;
+        GICLR1, 1, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR2, 2, 1,  u8;
+        GICLR2, 2, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR3, 3, 1,  u8;
+        GICLR3, 3, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR4, 4, 1,  u8;
+        GICLR4, 4, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR5, 5, 1,  u8;
+        GICLR5, 5, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR6, 6, 1,  u8;
+        GICLR6, 6, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR7, 7, 1,  u8;
+        GICLR7, 7, 1,  bool;
         ///   GPIO IRQ latch clear for GPIO input
-        GICLR8, 8, 1,  u8;
+        GICLR8, 8, 1,  bool;
     }
     /// GPIO Interrupt De-bounce Enable
-    [0x05, 0x28, 4, RW, GPIO_IDBE(gpio_idbe)] {
+    [0x05, 0x28, 4, RW, GPIO_IDBE(gpio_idbe), 0x00] {
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE0, 0, 1,  u8;
+        GIDBE0, 0, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE1, 1, 1,  u8;
+        GIDBE1, 1, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE2, 2, 1,  u8;
+        GIDBE2, 2, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE3, 3, 1,  u8;
+        GIDBE3, 3, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE4, 4, 1,  u8;
+        GIDBE4, 4, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE5, 5, 1,  u8;
+        GIDBE5, 5, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE6, 6, 1,  u8;
+        GIDBE6, 6, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE7, 7, 1,  u8;
+        GIDBE7, 7, 1,  bool;
         ///   GPIO IRQ de-bounce enable for GPIO
-        GIDBE8, 8, 1,  u8;
+        GIDBE8, 8, 1,  bool;
     }
     /// GPIO Raw State
-    [0x05, 0x2C, 2, RO, GPIO_RAW(gpio_raw)] {
+    [0x05, 0x2C, 2, RO, GPIO_RAW(gpio_raw), ?] {
         ///   GPIO port raw state
-        GRAWP0, 0, 1,  u8;
+        GRAWP0, 0, 1,  bool;
         ///   GPIO port raw state
-        GRAWP1, 1, 1,  u8;
+        GRAWP1, 1, 1,  bool;
         ///   GPIO port raw state
-        GRAWP2, 2, 1,  u8;
+        GRAWP2, 2, 1,  bool;
         ///   GPIO port raw state
-        GRAWP3, 3, 1,  u8;
+        GRAWP3, 3, 1,  bool;
         ///   GPIO port raw state
-        GRAWP4, 4, 1,  u8;
+        GRAWP4, 4, 1,  bool;
         ///   GPIO port raw state
-        GRAWP5, 5, 1,  u8;
+        GRAWP5, 5, 1,  bool;
         ///   GPIO port raw state
-        GRAWP6, 6, 1,  u8;
+        GRAWP6, 6, 1,  bool;
         ///   GPIO port raw state
-        GRAWP7, 7, 1,  u8;
+        GRAWP7, 7, 1,  bool;
         ///   GPIO port raw state
-        GRAWP8, 8, 1,  u8;
+        GRAWP8, 8, 1,  bool;
     }
     /// PAC configuration
-    [0x06, 0x00, 2, RW, DTUNE0(dtune0)] {
+    [0x06, 0x00, 2, RW, DTUNE0(dtune0), 0x100C] {
         ///   Preamble Acquisition Chunk size
-        PAC, 0, 2,  u8;
+        PAC, 0, 2,  PacSize(u8) {
+            /// 8 symbols, recommended for a preamble length of 128 symbols.
+            Pac8 = 0b00,
+            /// 16 symbols, recommended for a preamble length of 256 symbols.
+            Pac16 = 0b01,
+            /// 32 symbols, recommended for a preamble length of 512 symbols or longer.
+            Pac32 = 0b10,
+            /// 4 symbols, recommended for a preamble length of 64 symbols or shorter.
+            Pac4 = 0b11,
+        };
         ///   Tuning bit 4 of digital tuning reg0
-        DT0B4, 4, 1,  u8;
+        DT0B4, 4, 1,  bool;
     }
     /// SFD timeout
-    [0x06, 0x02, 2, RW, RX_SFD_TOC(rx_sfd_toc)] {
+    [0x06, 0x02, 2, RW, RX_SFD_TOC(rx_sfd_toc), 0x0081] {
         /// don't set to 0
         VALUE, 0, 16,  u16;
     }
     /// Preamble detection timeout
-    [0x06, 0x04, 2, RW, PRE_TOC(pre_toc)] {
+    [0x06, 0x04, 2, RW, PRE_TOC(pre_toc), 0x00] {
         /// digital receiver configuration
         VALUE, 0, 16,  u16;
     }
     /// Receiver tuning register
-    [0x06, 0x0C, 4, RW, DTUNE3(dtune3)] {
+    [0x06, 0x0C, 4, RW, DTUNE3(dtune3), 0xAF5F_584C] {
         /// value
         VALUE, 0, 32,  u32;
     }
     /// Digital Tuning Reserved register
-    [0x06, 0x10, 4, RW, DTUNE4(dtune4)] {
+    [0x06, 0x10, 4, RW, DTUNE4(dtune4), ?] {
         /// value
         VALUE, 24, 8,  u32;
     }
     /// Digital Tuning Reserved register
-    [0x06, 0x14, 4, RO, DTUNE5(dtune5)] {
+    [0x06, 0x14, 4, RO, DTUNE5(dtune5), ?] {
         /// value
         VALUE, 0, 32,  u32;
     }
     /// Carrier recovery integrator register
-    [0x06, 0x29, 3, RO, DRX_CAR_INT(drx_car_int)] {
+    [0x06, 0x29, 3, RO, DRX_CAR_INT(drx_car_int), ?] {
         /// value
         VALUE, 0, 24,  u32;
     }
     /// RF control enable
-    [0x07, 0x00, 4, RW, RF_ENABLE(rf_enable)] {
+    [0x07, 0x00, 4, RW, RF_ENABLE(rf_enable), 0x00] {
         /// value
         VALUE, 0, 32,  u32;
     }
     /// RF enable mask
-    [0x07, 0x04, 4, RW, RF_CTRL_MASK(rf_ctrl_mask)] {
+    [0x07, 0x04, 4, RW, RF_CTRL_MASK(rf_ctrl_mask), 0x00] {
         /// value
         VALUE, 0, 32,  u32;
     }
     /// RF switch configuration
-    [0x07, 0x14, 4, RW, RF_SWITCH(rf_switch)] {
+    [0x07, 0x14, 4, RW, RF_SWITCH(rf_switch), ?] {
         /// When set to 1, the automatic toggling of the antenna switch is disabled when the device is operating in PDoA modes
-        ANTSWNOTOGGLE, 0, 1,  u8;
+        ANTSWNOTOGGLE, 0, 1,  bool;
         /// Specifies the starting port for reception when the device is operating in PDoA modes
-        ANTSWPDOAPORT, 1, 1,  u8;
+        ANTSWPDOAPORT, 1, 1,  bool;
         /// Setting this to 1 will enable manual control of the antenna switch
-        ANTSWEN, 8, 1,  u8;
+        ANTSWEN, 8, 1,  bool;
         /// Manual control of antenna switch when ANTSWEN is set
         ANTSWCTRL, 12, 3,  u8;
         /// Setting this to 1 will enable manual control of the TX RX switch
-        TRXSWEN, 16, 1,  u8;
+        TRXSWEN, 16, 1,  bool;
         /// TX/RX switch control when TRXSWEN bit is set
         TRXSWCTRL, 24, 6,  u8;
     }
     /// RF transmitter configuration
-    [0x07, 0x1A, 1, RW, RF_TX_CTRL_1(rf_tx_ctrl_1)] {
+    [0x07, 0x1A, 1, RW, RF_TX_CTRL_1(rf_tx_ctrl_1), ?] {
         /// value
         VALUE, 0, 8,  u8;
     }
     /// RF transmitter configuration
-    [0x07, 0x1C, 4, RW, RF_TX_CTRL_2(rf_tx_ctrl_2)] {
+    [0x07, 0x1C, 4, RW, RF_TX_CTRL_2(rf_tx_ctrl_2), ?] {
         /// Pulse Generator Delay value
         VALUE, 0, 32,  u32;
     }
     /// Transmitter test configuration
-    [0x07, 0x28, 1, RW, TX_TEST(tx_test)] {
+    [0x07, 0x28, 1, RW, TX_TEST(tx_test), 0x00] {
         /// Transmitter test enable
         TX_ENTEST, 0, 4,  u8;
     }
     /// Transmitter Calibration – SAR temperaturesensor read enable
-    [0x07, 0x34, 1, RW, SAR_TEST(rsar_test)] {
+    [0x07, 0x34, 1, RW, SAR_TEST(rsar_test), 0x00] {
         /// Writing 1 enables the SAR temperature sensor reading
-        SAR_RDEN, 2, 1,  u8;
+        SAR_RDEN, 2, 1,  bool;
     }
     /// Internal LDO voltage tuning parameter
-    [0x07, 0x40, 8, RW, LDO_TUNE(ldo_tune)] {
+    [0x07, 0x40, 8, RW, LDO_TUNE(ldo_tune), ?] {
         ///  used to control the output voltage levels of the on chip LDOs
         VALUE, 0, 61,  u128;
     }
     /// LDO control
-    [0x07, 0x48, 4, RW, LDO_CTRL(ldo_ctrl)] {
+    [0x07, 0x48, 4, RW, LDO_CTRL(ldo_ctrl), ?] {
         ///  LDO control
         LOW, 0, 16,  u16;
         ///  LDO control
         HIGH, 16, 16,  u16;
     }
     /// LDO tuning register
-    [0x07, 0x51, 1, RW, LDO_RLOAD(ldo_rload)] {
+    [0x07, 0x51, 1, RW, LDO_RLOAD(ldo_rload), ?] {
         ///  LDO tuning register
         VALUE, 0, 8,  u8;
     }
     /// Transmitter Calibration – SAR control
-    [0x08, 0x00, 1, RW, SAR_CTRL(sar_ctrl)] {
+    [0x08, 0x00, 1, RW, SAR_CTRL(sar_ctrl), 0x00] {
         /// Writing 1 sets SAR enable and writing 0 clears the enable.
-        SAR_START, 0, 1,  u8;
+        SAR_START, 0, 1,  bool;
     }
     /// Transmitter Calibration – SAR  status
-    [0x08, 0x04, 1, RW, SAR_STATUS(sar_status)] {
+    [0x08, 0x04, 1, RW, SAR_STATUS(sar_status), 0x00] {
         /// Set to 1 when the data is ready to be read.
-        SAR_DONE, 0, 1,  u8;
+        SAR_DONE, 0, 1,  bool;
     }
     /// Transmitter Calibration –Latest SAR readings
-    [0x08, 0x08, 3, RO, SAR_READING(sar_reading)] {
+    [0x08, 0x08, 3, RO, SAR_READING(sar_reading), ?] {
         /// Latest SAR reading for Voltage level.
         SAR_LVBAT, 0, 8,  u8;
         /// Latest SAR reading for Temperature level.
         SAR_LTEMP, 8, 8,  u8;
     }
     /// Transmitter Calibration – SAR readings at last wake-up
-    [0x08, 0x0C, 2, RO, SAR_WAKE_RD(sar_wake_rd)] {
+    [0x08, 0x0C, 2, RO, SAR_WAKE_RD(sar_wake_rd), ?] {
         /// SAR reading of Voltage level taken at last wake up event.
         SAR_WVBAT, 0, 8,  u8;
         /// To read the temp, use SAR_READING instead.
         SAR_WTEMP, 8, 8,  u8;
     }
     /// Transmitter Calibration – Pulse Generator control
-    [0x08, 0x10, 2, RW, PGC_CTRL(pgc_ctrl)] {
+    [0x08, 0x10, 2, RW, PGC_CTRL(pgc_ctrl), ?] {
         /// Start the pulse generator calibration.
-        PG_START, 0, 1,  u8;
+        PG_START, 0, 1,  bool;
         /// Start the pulse generator auto-calibration.
-        PGC_AUTO_CAL, 1, 1,  u8;
+        PGC_AUTO_CAL, 1, 1,  bool;
         /// Number of clock cycles over which to run the pulse generator calibration counter.
         PGC_TMEAS, 2, 4,  u8;
     }
     /// Transmitter Calibration – Pulse Generator status
-    [0x08, 0x14, 2, RO, PGC_STATUS(pgc_status)] {
+    [0x08, 0x14, 2, RO, PGC_STATUS(pgc_status), ?] {
         /// Pulse generator count value
         PG_DELAY_CNT, 0, 12,  u16;
         /// Auto-calibration of the PG_DELAY  has completed.
-        AUTOCAL_DONE, 12, 1,  u8;
+        AUTOCAL_DONE, 12, 1,  bool;
     }
     /// Transmitter Calibration – Pulse Generator test
-    [0x08, 0x18, 2, RW, PG_TEST(pg_test)] {
+    [0x08, 0x18, 2, RW, PG_TEST(pg_test), ?] {
         /// Pulse Generator test
         VALUE, 0, 16,  u16;
     }
     /// Transmitter Calibration – Pulse Generator count target value
-    [0x08, 0x1C, 2, RO, PG_CAL_TARGET(pg_cal_target)] {
+    [0x08, 0x1C, 2, RO, PG_CAL_TARGET(pg_cal_target), ?] {
         /// Pulse generator target value of PG_COUNT at which point PG auto cal will complete.
         VALUE, 0, 12,  u16;
     }
     /// PLL configuration
-    [0x09, 0x00, 2, RW, PLL_CFG(pll_cfg)] {
+    [0x09, 0x00, 2, RW, PLL_CFG(pll_cfg), 0x1F3C] {
         /// PLL configuration
         VALUE, 0, 16,  u16;
     }
     /// PLL coarse code – starting code for calibration procedure
-    [0x09, 0x04, 4, RW, PLL_CC(pll_cc)] {
+    [0x09, 0x04, 4, RW, PLL_CC(pll_cc), ?] {
         /// PLL calibration coarse code for channel 9.
         CH9_CODE, 0, 8,  u8;
         /// PLL calibration coarse code for channel 5.
         CH5_CODE, 8, 14,  u16;
         /// PLL calibration coarse code.
-        VALUE, 0, 32,  u32;
+        VALUE, 0, 32,  u32, alias;
     }
     /// PLL calibration configuration
-    [0x09, 0x08, 2, RW, PLL_CAL(pll_cal)] {
+    [0x09, 0x08, 2, RW, PLL_CAL(pll_cal), ?] {
         /// Use the coarse code value as set in PLL_CC register as starting point for PLL calibration.
-        USE_OLD, 1, 1,  u8;
+        USE_OLD, 1, 1,  bool;
         /// PLL calibration configuration value.
         PLL_CFG_LD, 4, 4,  u8;
         /// PLL  calibration  enable  bit.
-        CAL_EN, 8, 1,  u8;
+        CAL_EN, 8, 1,  bool;
     }
     /// Frequency synthesiser – Crystal trim
-    [0x09, 0x14, 1, RW, XTAL(xtal)] {
+    [0x09, 0x14, 1, RW, XTAL(xtal), ?] {
         /// Crystal Trim.
         VALUE, 0, 8,  u8;
     }
     /// AON wake up configuration register
-    [0x0A, 0x00, 3, RW, AON_DIG_CFG(aon_dig_cfg)] {
+    [0x0A, 0x00, 3, RW, AON_DIG_CFG(aon_dig_cfg), ?] {
         /// On Wake-up download the AON array.
-        ONW_AON_DLD, 0, 1,  u8;
+        ONW_AON_DLD, 0, 1,  bool;
         /// On Wake-up Run the (temperature and voltage) Analog-to-Digital Convertors.
-        ONW_RUN_SAR, 1, 1,  u8;
+        ONW_RUN_SAR, 1, 1,  bool;
         /// On Wake-up go to IDLE_PLL state.
-        ONW_GO2IDLE, 8, 1,  u8;
+        ONW_GO2IDLE, 8, 1,  bool;
         /// On Wake-up go to RX.
-        ONW_GO2RX, 9, 1,  u8;
+        ONW_GO2RX, 9, 1,  bool;
         /// On Wake-up perform RX calibration
-        ONW_PGFCAL, 11, 1,  u8;
+        ONW_PGFCAL, 11, 1,  bool;
     }
     /// AON control register
-    [0x0A, 0x04, 1, RW, AON_CTRL(aon_ctrl)] {
+    [0x0A, 0x04, 1, RW, AON_CTRL(aon_ctrl), 0x00] {
         /// Copy the user configurations from the AON memory to the host interface register set.
-        RESTORE, 0, 1,  u8;
+        RESTORE, 0, 1,  bool;
         /// Copy the user configurations from the host interface register  set  into  the  AON  memory.
-        SAVE, 1, 1,  u8;
+        SAVE, 1, 1,  bool;
         /// Upload the AON block configurations to the AON.
-        CFG_UPLOAD, 2, 1,  u8;
+        CFG_UPLOAD, 2, 1,  bool;
         /// Direct AON memory access read.
-        DCA_READ, 3, 1,  u8;
+        DCA_READ, 3, 1,  bool;
         /// Direct AON memory write access
-        DCA_WRITE, 4, 1,  u8;
+        DCA_WRITE, 4, 1,  bool;
         /// Direct AON memory write access. Needs to be set when using address > 0xFF
-        DCA_WRITE_HI, 5, 1,  u8;
+        DCA_WRITE_HI, 5, 1,  bool;
         /// Direct AON memory access enable bit.
-        DCA_ENAB, 7, 1,  u8;
+        DCA_ENAB, 7, 1,  bool;
     }
     /// AON direct access read data result
-    [0x0A, 0x08, 1, RW, AON_RDATA(aon_rdata)] {
+    [0x0A, 0x08, 1, RW, AON_RDATA(aon_rdata), 0x00] {
         /// AON direct access read data result
         VALUE, 0, 8,  u8;
     }
     /// AON direct access address
-    [0x0A, 0x0C, 2, RW, AON_ADDR(aon_addr)] {
+    [0x0A, 0x0C, 2, RW, AON_ADDR(aon_addr), 0x00] {
         /// AON direct access address
         VALUE, 0, 16,  u16;
     }
     /// AON direct access write data
-    [0x0A, 0x10, 1, RW, AON_WDATA(aon_wdata)] {
+    [0x0A, 0x10, 1, RW, AON_WDATA(aon_wdata), 0x00] {
         /// AON direct access write data
         VALUE, 0, 8,  u8;
     }
     /// AON configuration register
-    [0x0A, 0x14, 1, RW, AON_CFG(aon_cfg)] {
+    [0x0A, 0x14, 1, RW, AON_CFG(aon_cfg), ?] {
         /// Sleep enable configuration bit.
-        SLEEP_EN, 0, 1,  u8;
+        SLEEP_EN, 0, 1,  bool;
         /// Wake when sleep counter elapses.
-        WAKE_CNT, 1, 1,  u8;
+        WAKE_CNT, 1, 1,  bool;
         /// Enable the BROWNOUT detector during SLEEP or DEEPSLEEP.
-        BROUT_EN, 2, 1,  u8;
+        BROUT_EN, 2, 1,  bool;
         /// Wake using SPI access.
-        WAKE_CSN, 3, 1,  u8;
+        WAKE_CSN, 3, 1,  bool;
         /// Wake using WAKEUP pin.
-        WAKE_WUP, 4, 1,  u8;
+        WAKE_WUP, 4, 1,  bool;
         /// Preserve Sleep.
-        PRES_SLEEP, 5, 1,  u8;
+        PRES_SLEEP, 5, 1,  bool;
     }
     /// OTP data to program to a particular address
-    [0x0B, 0x00, 4, RW, OTP_WDATA(otp_wdata)] {
+    [0x0B, 0x00, 4, RW, OTP_WDATA(otp_wdata), 0x00] {
         /// OTP data to program to a particular address
         VALUE, 0, 32,  u32;
     }
     /// OTP address to which to program the data
-    [0x0B, 0x04, 4, RW, OTP_ADDR(otp_addr)] {
+    [0x0B, 0x04, 4, RW, OTP_ADDR(otp_addr), 0x00] {
         /// Address within OTP memory that will be accessed read or written.
         VALUE, 0, 11,  u16;
     }
     /// OTP configuration register
-    [0x0B, 0x08, 2, RW, OTP_CFG(otp_cfg)] {
+    [0x0B, 0x08, 2, RW, OTP_CFG(otp_cfg), ?] {
         /// Enable manual control over OTP interface.
-        OTP_MAN, 0, 1,  u8;
+        OTP_MAN, 0, 1,  bool;
         /// OTP read enable.
-        OTP_READ, 1, 1,  u8;
+        OTP_READ, 1, 1,  bool;
         /// OTP write enable.
-        OTP_WRITE, 2, 1,  u8;
+        OTP_WRITE, 2, 1,  bool;
         /// OTP write mode.
-        OTP_WRITE_MR, 3, 1,  u8;
+        OTP_WRITE_MR, 3, 1,  bool;
         /// Loading of the RX_TUNE_CAL parameter
-        DGC_KICK, 6, 1,  u8;
+        DGC_KICK, 6, 1,  bool;
         /// Loading of the LDOTUNE_CAL parameter
-        LDO_KICK, 7, 1,  u8;
+        LDO_KICK, 7, 1,  bool;
         /// Loading of the BIASTUNE_CAL parameter
-        BIAS_KICK, 8, 1,  u8;
+        BIAS_KICK, 8, 1,  bool;
         /// Loading of the operating parameter set selected by the OPS_SEL configuration
-        OPS_KICK, 10, 1,  u8;
+        OPS_KICK, 10, 1,  bool;
         /// Operating parameter set selection.
         OPS_SEL, 11, 2,  u8;
         /// RX_TUNE parameter set selection.
-        DGC_SEL, 13, 1,  u8;
+        DGC_SEL, 13, 1,  bool;
     }
     /// OTP memory programming status register
-    [0x0B, 0x0C, 1, RW, OTP_STAT(otp_stat)] {
+    [0x0B, 0x0C, 1, RW, OTP_STAT(otp_stat), ?] {
         /// OTP Programming Done
-        OTP_PROG_DONE, 0, 1,  u8;
+        OTP_PROG_DONE, 0, 1,  bool;
         /// OTP Programming Voltage OK.
-        OTP_VPP_OK, 1, 1,  u8;
+        OTP_VPP_OK, 1, 1,  bool;
     }
     /// OTP data read from given address
-    [0x0B, 0x10, 4, RO, OTP_RDATA(otp_rdata)] {
+    [0x0B, 0x10, 4, RO, OTP_RDATA(otp_rdata), ?] {
         /// OTP data read from given address
         VALUE, 0, 32,  u32;
     }
     /// OTP Special Register (SR) read data
-    [0x0B, 0x14, 4, RW, OTP_SRDATA(otp_srdata)] {
+    [0x0B, 0x14, 4, RW, OTP_SRDATA(otp_srdata), 0x00] {
         /// OTP Special Register (SR) read data
         VALUE, 0, 32,  u32;
     }
     /// Preamble sequence receive time stamp and status
-    [0x0C, 0x00, 8, RO, IP_TS(ip_ts)] {
+    [0x0C, 0x00, 8, RO, IP_TS(ip_ts), 0x00] {
         /// Preamble sequence Time of Arrival estimate.
         IP_TOA, 0, 40,  u64;
         /// Phase of arrival as computed from the preamble CIR.
@@ -1592,7 +2369,7 @@ impl_registers! {
         IP_TOAST, 56, 8,  u8;
     }
     /// STS receive time stamp and status
-    [0x0C, 0x08, 8, RO, STS_TS(sts_ts)] {
+    [0x0C, 0x08, 8, RO, STS_TS(sts_ts), 0x00] {
         /// STS Time of Arrival estimate.
         STS_TOA, 0, 40,  u64;
         /// Phase of arrival as computed from the STS CIR.
@@ -1601,7 +2378,7 @@ impl_registers! {
         STS_TOAST, 55, 9,  u16;
     }
     /// 2nd STS receive time stamp and status
-    [0x0C, 0x10, 8, RO, STS1_TS(sts1_ts)] {
+    [0x0C, 0x10, 8, RO, STS1_TS(sts1_ts), 0x00] {
         /// STS second Time of Arrival estimate.
         STS1_TOA, 0, 40,  u64;
         /// Phase of arrival as computed from the STS based CIR estimate.
@@ -1610,175 +2387,175 @@ impl_registers! {
         STS1_TOAST, 55, 9,  u16;
     }
     /// The TDoA between the two CIRs
-    [0x0C, 0x18, 6, RO, TDOA(tdoa)] {
+    [0x0C, 0x18, 6, RO, TDOA(tdoa), 0x00] {
         /// The TDoA between the two CIRs
         VALUE, 0, 48,  u64;
     }
     /// The PDoA between the two CIRs
-    [0x0C, 0x1E, 2, RO, PDOA(pdoa)] {
+    [0x0C, 0x1E, 2, RO, PDOA(pdoa), 0x00] {
         /// Phase difference result.
         VALUE, 0, 14,  u16;
         /// First path threshold test mode.
-        FP_TH_MD, 14, 1,  u8;
+        FP_TH_MD, 14, 1,  bool;
     }
     /// CIA Diagnostic 0
-    [0x0C, 0x20, 4, RO, CIA_DIAG_0(cia_diag_0)] {
+    [0x0C, 0x20, 4, RO, CIA_DIAG_0(cia_diag_0), 0x00] {
         /// Clock offset estimate.
         COE_PPM, 0, 13,  u16;
     }
     /// Reserved diagnostic data
-    [0x0C, 0x24, 4, RO, CIA_DIAG_1(cia_diag_1)] {
+    [0x0C, 0x24, 4, RO, CIA_DIAG_1(cia_diag_1), 0x00] {
     }
     /// Preamble Diagnostic 0 – peak
-    [0x0C, 0x28, 4, RO, IP_DIAG_0(ip_diag_0)] {
+    [0x0C, 0x28, 4, RO, IP_DIAG_0(ip_diag_0), 0x00] {
         /// Amplitude of the sample accumulated using the preamble sequence.
         IP_PEAKA, 0, 21,  u32;
         /// Index of the sample accumulated using the preamble sequence.
         IP_PEAKI, 21, 10,  u16;
     }
     /// Preamble Diagnostic 1 – power indication
-    [0x0C, 0x2C, 4, RO, IP_DIAG_1(ip_diag_1)] {
+    [0x0C, 0x2C, 4, RO, IP_DIAG_1(ip_diag_1), 0x00] {
         /// Channel area accumulated using the preamble sequence.
         IP_CAREA, 0, 17,  u32;
     }
     /// Preamble Diagnostic 2 – magnitude @ FP + 1
-    [0x0C, 0x30, 4, RO, IP_DIAG_2(ip_diag_2)] {
+    [0x0C, 0x30, 4, RO, IP_DIAG_2(ip_diag_2), 0x00] {
         /// Magnitude of the sample at the first index immediately after the estimated first path position accumulated using the preamble sequence.
         IP_FP1M, 0, 22,  u32;
     }
     /// Preamble Diagnostic 3 – magnitude @ FP + 2
-    [0x0C, 0x34, 4, RO, IP_DIAG_3(ip_diag_3)] {
+    [0x0C, 0x34, 4, RO, IP_DIAG_3(ip_diag_3), 0x00] {
         /// Magnitude of the sample at the second index immediately after the estimated first path position accumulated using the preamble sequence.
         IP_FP2M, 0, 22,  u32;
     }
     /// Preamble Diagnostic 4 – magnitude @ FP + 3
-    [0x0C, 0x38, 4, RO, IP_DIAG_4(ip_diag_4)] {
+    [0x0C, 0x38, 4, RO, IP_DIAG_4(ip_diag_4), 0x00] {
         /// Magnitude of the sample at the third index immediately after the estimated first path position accumulated using the preamble sequence.
         IP_FP3M, 0, 22,  u32;
     }
     /// Reserved diagnostic data
-    [0x0C, 0x3C, 12, RO, IP_DIAG_RES1(ip_diag_res1)] {
+    [0x0C, 0x3C, 12, RO, IP_DIAG_RES1(ip_diag_res1), ?] {
     }
     /// Preamble Diagnostic 8 – first path
-    [0x0C, 0x48, 4, RO, IP_DIAG_8(ip_diag_8)] {
+    [0x0C, 0x48, 4, RO, IP_DIAG_8(ip_diag_8), 0x00] {
         /// Estimated first path location accumulated using the preamble sequence.
         IP_FP, 0, 16,  u16;
     }
     /// Reserved diagnostic data
-    [0x0C, 0x4C, 12, RO, IP_DIAG_RES2(ip_diag_res2)] {
+    [0x0C, 0x4C, 12, RO, IP_DIAG_RES2(ip_diag_res2), ?] {
     }
     /// Preamble Diagnostic 12 – symbols accumulated
-    [0x0C, 0x58, 4, RO, IP_DIAG_12(ip_diag_12)] {
+    [0x0C, 0x58, 4, RO, IP_DIAG_12(ip_diag_12), 0x00] {
         /// Number of preamble sequence symbols that were accumulated to form the preamble CIR.
         IP_NACC, 0, 12,  u16;
     }
     /// STS 0 Diagnostic 0 – STS CIA peak amplitude
-    [0x0C, 0x5C, 4, RO, STS_DIAG_0(sts_diag_0)] {
+    [0x0C, 0x5C, 4, RO, STS_DIAG_0(sts_diag_0), 0x00] {
         /// Amplitude of the sample accumulated using the STS
         CP0_PEAKA, 0, 21,  u32;
         /// Index of the sample accumulated using the STS
         CP0_PEAKI, 21, 9,  u16;
     }
     /// STS 0 Diagnostic 1 – STS power indication
-    [0x0C, 0x60, 4, RO, STS_DIAG_1(sts_diag_1)] {
+    [0x0C, 0x60, 4, RO, STS_DIAG_1(sts_diag_1), 0x00] {
         /// Channel area accumulated using the the STS
         CP0_CAREA, 0, 16,  u16;
     }
     /// STS 0 Diagnostic 2 – STS magnitude @ FP + 1
-    [0x0C, 0x64, 4, RO, STS_DIAG_2(sts_diag_2)] {
+    [0x0C, 0x64, 4, RO, STS_DIAG_2(sts_diag_2), 0x00] {
         /// Magnitude of the sample at the first index immediately after the estimated first path position accumulated using the STS
         CP0_FP1M, 0, 22,  u32;
     }
     /// STS 0 Diagnostic 3 – STS magnitude @ FP + 2
-    [0x0C, 0x68, 4, RO, STS_DIAG_3(sts_diag_3)] {
+    [0x0C, 0x68, 4, RO, STS_DIAG_3(sts_diag_3), 0x00] {
         /// Magnitude of the sample at the second index immediately after the estimated first path position accumulated using the STS
         CP0_FP2M, 0, 22,  u32;
     }
     /// STS 0 Diagnostic 4 – STS magnitude @ FP + 3
-    [0x0D, 0x00, 4, RO, STS_DIAG_4(sts_diag_4)] {
+    [0x0D, 0x00, 4, RO, STS_DIAG_4(sts_diag_4), 0x00] {
         /// Magnitude of the sample at the third index immediately after the estimated first path position accumulated using the STS
         CP0_FP3M, 0, 22,  u32;
     }
     /// Reserved diagnostic data
-    [0x0D, 0x04, 12, RO, STS0_DIAG_RES1(sts0_diag_res1)] {
+    [0x0D, 0x04, 12, RO, STS0_DIAG_RES1(sts0_diag_res1), ?] {
     }
     /// STS 0 Diagnostic 8 – STS first path
-    [0x0D, 0x10, 4, RO, STS_DIAG_8(sts_diag_8)] {
+    [0x0D, 0x10, 4, RO, STS_DIAG_8(sts_diag_8), 0x00] {
         /// Estimated first path location accumulated using the STS
         CP0_FP, 0, 15,  u16;
     }
     /// Reserved diagnostic data
-    [0x0D, 0x14, 12, RO, STS0_DIAG_RES2(sts0_diag_res2)] {
+    [0x0D, 0x14, 12, RO, STS0_DIAG_RES2(sts0_diag_res2), ?] {
     }
     /// STS 0 diagnostic 12 – accumulated STS length
-    [0x0D, 0x20, 4, RO, STS_DIAG_12(sts_diag_12)] {
+    [0x0D, 0x20, 4, RO, STS_DIAG_12(sts_diag_12), 0x00] {
         /// Number of preamble sequence symbols that were accumulated to form the preamble CIR.
         CP0_NACC, 0, 11,  u16;
     }
     /// Reserved diagnostic data
-    [0x0D, 0x24, 20, RO, STS0_DIAG_RES3(sts0_diag_res3)] {
+    [0x0D, 0x24, 20, RO, STS0_DIAG_RES3(sts0_diag_res3), ?] {
     }
     /// STS 1 Diagnostic 0 – STS CIA peak amplitude
-    [0x0D, 0x38, 4, RO, STS1_DIAG_0(sts1_diag_0)] {
+    [0x0D, 0x38, 4, RO, STS1_DIAG_0(sts1_diag_0), 0x00] {
         /// Amplitude of the sample accumulated using the STS
         CP1_PEAKA, 0, 21,  u32;
         /// Index of the sample accumulated using the STS
         CP1_PEAKI, 21, 9,  u16;
     }
     /// STS 1 Diagnostic 1 – STS power indication
-    [0x0D, 0x3C, 4, RO, STS1_DIAG_1(sts1_diag_1)] {
+    [0x0D, 0x3C, 4, RO, STS1_DIAG_1(sts1_diag_1), 0x00] {
         /// Channel area accumulated using the the STS
         CP1_CAREA, 0, 16,  u16;
     }
     /// STS 1 Diagnostic 2 – STS magnitude @ FP + 1
-    [0x0D, 0x40, 4, RO, STS1_DIAG_2(sts1_diag_2)] {
+    [0x0D, 0x40, 4, RO, STS1_DIAG_2(sts1_diag_2), 0x00] {
         /// Magnitude of the sample at the first index immediately after the estimated first path position accumulated using the STS
         CP1_FP1M, 0, 22,  u32;
     }
     /// STS 1 Diagnostic 3 – STS magnitude @ FP + 2
-    [0x0D, 0x44, 4, RO, STS1_DIAG_3(sts1_diag_3)] {
+    [0x0D, 0x44, 4, RO, STS1_DIAG_3(sts1_diag_3), 0x00] {
         /// Magnitude of the sample at the second index immediately after the estimated first path position accumulated using the STS
         CP1_FP2M, 0, 22,  u32;
     }
     /// STS 1 Diagnostic 4 – STS magnitude @ FP + 3
-    [0x0D, 0x48, 4, RO, STS1_DIAG_4(sts1_diag_4)] {
+    [0x0D, 0x48, 4, RO, STS1_DIAG_4(sts1_diag_4), 0x00] {
         /// Magnitude of the sample at the third index immediately after the estimated first path position accumulated using the STS
         CP1_FP3M, 0, 22,  u32;
     }
     /// Reserved diagnostic data
-    [0x0D, 0x4C, 12, RO, STS1_DIAG_RES1(sts1_diag_res1)] {
+    [0x0D, 0x4C, 12, RO, STS1_DIAG_RES1(sts1_diag_res1), ?] {
     }
     /// STS 1 Diagnostic 8 – STS first path
-    [0x0D, 0x58, 4, RO, STS1_DIAG_8(sts1_diag_8)] {
+    [0x0D, 0x58, 4, RO, STS1_DIAG_8(sts1_diag_8), 0x00] {
         /// Estimated first path location accumulated using the STS
         CP1_FP, 0, 15,  u16;
     }
     /// Reserved diagnostic data
-    [0x0D, 0x5C, 12, RO, STS1_DIAG_RES2(sts1_diag_res2)] {
+    [0x0D, 0x5C, 12, RO, STS1_DIAG_RES2(sts1_diag_res2), ?] {
     }
     /// STS 1 Diagnostic 12 – STS accumulated STS length
-    [0x0D, 0x68, 4, RO, STS1_DIAG_12(sts1_diag_12)] {
+    [0x0D, 0x68, 4, RO, STS1_DIAG_12(sts1_diag_12), 0x00] {
         /// Number of preamble sequence symbols that were accumulated to form the preamble CIR.
         CP1_NACC, 0, 11,  u16;
     }
     /// CIA general configuration
-    [0x0E, 0x00, 4, RW, CIA_CONF(cia_conf)] {
+    [0x0E, 0x00, 4, RW, CIA_CONF(cia_conf), ?] {
         /// Configures the receive antenna delay.
         RXANTD, 0, 16,  u16;
         ///  Minimum Diagnostics.
-        MINDIAG, 20, 1,  u8;
+        MINDIAG, 20, 1,  bool;
     }
     /// First path temp adjustment and thresholds
-    [0x0E, 0x04, 4, RW, FP_CONF(fp_conf)] {
+    [0x0E, 0x04, 4, RW, FP_CONF(fp_conf), ?] {
         /// The threshold to use when performing the FP_AGREE test.
         FP_AGREED_TH, 8, 3,  u8;
         /// Temperature at which the device was calibrated.
         CAL_TEMP, 11, 8,  u8;
         /// Temperature compensation for RX antenna delay.
-        TC_RXDLY_EN, 20, 1,  u8;
+        TC_RXDLY_EN, 20, 1,  bool;
     }
     /// Preamble Config – CIA preamble configuration
-    [0x0E, 0x0C, 4, RW, IP_CONF_LO(ip_conf_lo)] {
+    [0x0E, 0x0C, 4, RW, IP_CONF_LO(ip_conf_lo), ?] {
         /// Preamble Noise Threshold Multiplier.
         IP_NTM, 0, 5,   u8;
         /// Preamble Peak Multiplier.
@@ -1789,12 +2566,12 @@ impl_registers! {
         IP_RTM, 16, 5,  u8;
     }
     /// Preamble Config – CIA preamble configuration
-    [0x0E, 0x0E, 4, RW, IP_CONF_HI(ip_conf_hi)] {
+    [0x0E, 0x0E, 4, RW, IP_CONF_HI(ip_conf_hi), ?] {
         /// Undocumented IP_CONF_HI register
         VALUE, 0, 32,  u32;
     }
     /// STS Config 0 – CIA STS configuration
-    [0x0E, 0x12, 4, RW, STS_CONF_0(sts_conf_0)] {
+    [0x0E, 0x12, 4, RW, STS_CONF_0(sts_conf_0), ?] {
         /// STS Noise Threshold Multiplier.
         STS_NTM, 0, 5,  u8;
         /// STS Peak Multiplier.
@@ -1805,123 +2582,123 @@ impl_registers! {
         STS_RTM, 16, 7,  u8;
     }
     /// STS Config 1 – CIA STS configuration
-    [0x0E, 0x16, 4, RW, STS_CONF_1(sts_conf_1)] {
+    [0x0E, 0x16, 4, RW, STS_CONF_1(sts_conf_1), ?] {
         /// Tuning value
         RES_B0, 0, 8,  u8;
         /// Checks to see if the two ToA estimates are within allowed tolerances.
-        FP_AGREED_EN, 28, 1,  u8;
+        FP_AGREED_EN, 28, 1,  bool;
         /// Checks how consistent the impulse response stays during the accumulation of the STS.
-        STS_CQ_EN, 29, 1,  u8;
+        STS_CQ_EN, 29, 1,  bool;
         /// Compare the sampling statistics of the STS reception to those of the earlier reception of the preamble sequence.
-        STS_SS_EN, 30, 1,  u8;
+        STS_SS_EN, 30, 1,  bool;
         /// Test the growth rate of the STS based CIR to the earlier growth rate of the preamble based CIR.
-        STS_PGR_EN, 31, 1,  u8;
+        STS_PGR_EN, 31, 1,  bool;
     }
     /// User adjustment to the PDoA
-    [0x0E, 0x1A, 2, RW, CIA_ADJUST(cia_adjust)] {
+    [0x0E, 0x1A, 2, RW, CIA_ADJUST(cia_adjust), ?] {
         /// Adjustment value to account for non-balanced antenna circuits.
-        VALUE, 0, 14,  u8;
+        VALUE, 0, 14,  u16;
     }
     /// Event counter control
-    [0x0F, 0x00, 1, RW, EVC_CTRL(evc_ctrl)] {
+    [0x0F, 0x00, 1, RW, EVC_CTRL(evc_ctrl), 0x00] {
         /// Event Counters Enable.
-        EVC_EN, 0, 1,  u8;
+        EVC_EN, 0, 1,  bool;
         /// Event Counters Clear.
-        EVC_CLR, 1, 1,  u8;
+        EVC_CLR, 1, 1,  bool;
     }
     /// PHR error counter
-    [0x0F, 0x04, 2, RO, EVC_PHE(evc_phe)] {
+    [0x0F, 0x04, 2, RO, EVC_PHE(evc_phe), 0x00] {
         /// PHR Error Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// RSD error counter
-    [0x0F, 0x06, 2, RO, EVC_RSE(evc_rse)] {
+    [0x0F, 0x06, 2, RO, EVC_RSE(evc_rse), 0x00] {
         /// Reed Solomon decoder (Sync Loss) Error Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// Frame check sequence good counter
-    [0x0F, 0x08, 2, RO, EVC_FCG(evc_fcg)] {
+    [0x0F, 0x08, 2, RO, EVC_FCG(evc_fcg), 0x00] {
         /// Frame Check Sequence Good Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// Frame Check Sequence error counter
-    [0x0F, 0x0A, 2, RO, EVC_FCE(evc_fce)] {
+    [0x0F, 0x0A, 2, RO, EVC_FCE(evc_fce), 0x00] {
         /// Frame Check Sequence Error Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// Frame filter rejection counter
-    [0x0F, 0x0C, 1, RO, EVC_FFR(evc_ffr)] {
+    [0x0F, 0x0C, 1, RO, EVC_FFR(evc_ffr), 0x00] {
         /// Frame Filter Rejection Event Counter.
         VALUE, 0, 8,  u8;
     }
     /// RX overrun error counter
-    [0x0F, 0x0E, 1, RO, EVC_OVR (evc_ovr)] {
+    [0x0F, 0x0E, 1, RO, EVC_OVR (evc_ovr), 0x00] {
         /// RX Overrun Error Event Counter.
         VALUE, 0, 8,  u8;
     }
     /// SFD timeout counter
-    [0x0F, 0x10, 2, RO, EVC_STO(evc_sto)] {
+    [0x0F, 0x10, 2, RO, EVC_STO(evc_sto), 0x00] {
         /// SFD timeout errors Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// Preamble timeout counter
-    [0x0F, 0x12, 2, RO, EVC_PTO(evc_pto)] {
+    [0x0F, 0x12, 2, RO, EVC_PTO(evc_pto), 0x00] {
         /// Preamble  Detection  Timeout  Event  Counter.
         VALUE, 0, 12,  u16;
     }
     /// RX frame wait timeout counter
-    [0x0F, 0x14, 1, RO, EVC_FWTO(evc_fwto)] {
+    [0x0F, 0x14, 1, RO, EVC_FWTO(evc_fwto), 0x00] {
         /// RX  Frame  Wait  Timeout  Event  Counter.
         VALUE, 0, 8,  u8;
     }
     /// TX frame sent counter
-    [0x0F, 0x16, 2, RO, EVC_TXFS(evc_txfs)] {
+    [0x0F, 0x16, 2, RO, EVC_TXFS(evc_txfs), 0x00] {
         /// TX Frame Sent Event Counter.
         VALUE, 0, 12,  u16;
     }
     /// Half period warning counter
-    [0x0F, 0x18, 1, RO, EVC_HPW(evc_hpw)] {
+    [0x0F, 0x18, 1, RO, EVC_HPW(evc_hpw), 0x00] {
         /// Half Period Warning Event Counter.
         VALUE, 0, 8,  u8;
     }
     /// SPI write CRC error counter
-    [0x0F, 0x1A, 1, RO, EVC_SWCE(evc_swce)] {
+    [0x0F, 0x1A, 1, RO, EVC_SWCE(evc_swce), 0x00] {
         /// SPI write CRC error counter.
         VALUE, 0, 8,  u8;
     }
     /// Digital diagnostics reserved area 1
-    [0x0F, 0x1C, 8, RO, EVC_RES1(evc_res1)] {
+    [0x0F, 0x1C, 8, RO, EVC_RES1(evc_res1), ?] {
         /// Digital diagnostics reserved area 1
         VALUE, 0, 64,  u64;
     }
     /// Test mode control register
-    [0x0F, 0x24, 4, RW, DIAG_TMC(diag_tmc)] {
+    [0x0F, 0x24, 4, RW, DIAG_TMC(diag_tmc), ?] {
         /// Transmit Power Spectrum Test Mode.
-        TX_PSTM, 4, 1,  u8;
+        TX_PSTM, 4, 1,  bool;
         /// Host interrupt polarity.
-        HIRQ_POL, 21, 1,  u8;
+        HIRQ_POL, 21, 1,  bool;
         /// Enable the CIA watchdog.
-        CIA_WDEN, 24, 1,  u8;
+        CIA_WDEN, 24, 1,  bool;
         /// Run the CIA manually.
-        CIA_RUN, 26, 1,  u8;
+        CIA_RUN, 26, 1,  bool;
     }
     /// STS quality error counter
-    [0x0F, 0x28, 1, RO, EVC_CPQE(evc_cpqe)] {
+    [0x0F, 0x28, 1, RO, EVC_CPQE(evc_cpqe), 0x00] {
         /// STS quality error counter
         VALUE, 0, 8,  u8;
     }
     /// Low voltage warning error counter
-    [0x0F, 0x2A, 1, RO, EVC_VWARN(evc_vwarn)] {
+    [0x0F, 0x2A, 1, RO, EVC_VWARN(evc_vwarn), 0x00] {
         /// Low voltage warning error counter
         VALUE, 0, 8,  u8;
     }
     /// SPI mode
-    [0x0F, 0x2C, 1, RO, SPI_MODE(spi_mode)] {
+    [0x0F, 0x2C, 1, RO, SPI_MODE(spi_mode), ?] {
         /// SPI mode
         VALUE, 0, 2,  u8;
     }
     /// System states *
-    [0x0F, 0x30, 4, RO, SYS_STATE(sys_state)] {
+    [0x0F, 0x30, 4, RO, SYS_STATE(sys_state), ?] {
         /// Current Transmit State Machine value
         TX_STATE, 0, 4,  u8;
         /// Current Receive State Machine value
@@ -1930,126 +2707,151 @@ impl_registers! {
         PMSC_STATE, 16, 8,  u8;
     }
     /// Fast command status
-    [0x0F, 0x3C, 1, RO, FCMD_STAT(fcmd_stat)] {
+    [0x0F, 0x3C, 1, RO, FCMD_STAT(fcmd_stat), ?] {
         /// Fast command status.
         VALUE, 0, 5,  u8;
     }
     /// Current value of  the low 32-bits of the STS IV
-    [0x0F, 0x48, 4, RO, CTR_DBG(ctr_dbg)] {
+    [0x0F, 0x48, 4, RO, CTR_DBG(ctr_dbg), ?] {
         /// Current value of  the low 32-bits of the STS IV
         VALUE, 0, 32,  u32;
     }
     /// SPI CRC LFSR initialisation code
-    [0x0F, 0x4C, 1, RO, SPICRCINIT(spicrcinit)] {
+    [0x0F, 0x4C, 1, RO, SPICRCINIT(spicrcinit), ?] {
         /// SPI CRC LFSR initialisation code for the SPI CRC function.
         VALUE, 0, 8,  u8;
     }
     /// Soft reset of the device blocks
-    [0x11, 0x00, 2, RW, SOFT_RST(soft_rst)] {
+    [0x11, 0x00, 2, RW, SOFT_RST(soft_rst), 0x01FF] {
         /// Soft ARM reset
-        ARM_RST, 0, 1,  u8;
+        ARM_RST, 0, 1,  bool;
         /// Soft PRGN reset
-        PRGN_RST, 1, 1,  u8;
+        PRGN_RST, 1, 1,  bool;
         /// Soft CIA reset
-        CIA_RST, 2, 1,  u8;
+        CIA_RST, 2, 1,  bool;
         /// Soft BIST reset
-        BIST_RST, 3, 1,  u8;
+        BIST_RST, 3, 1,  bool;
         /// Soft RX reset
-        RX_RST, 4, 1,  u8;
+        RX_RST, 4, 1,  bool;
         /// Soft TX reset
-        TX_RST, 5, 1,  u8;
+        TX_RST, 5, 1,  bool;
         /// Soft HIF reset
-        HIF_RST, 6, 1,  u8;
+        HIF_RST, 6, 1,  bool;
         /// Soft PMSC reset
-        PMSC_RST, 7, 1,  u8;
+        PMSC_RST, 7, 1,  bool;
         /// Soft GPIO reset
-        GPIO_RST, 8, 1,  u8;
+        GPIO_RST, 8, 1,  bool;
     }
     /// PMSC clock control register
-    [0x11, 0x04, 4, RW, CLK_CTRL(clk_ctrl)] {
+    [0x11, 0x04, 4, RW, CLK_CTRL(clk_ctrl), 0x0030_0200] {
         /// System Clock Selection field.
-        SYS_CLK, 0, 2,  u8;
+        SYS_CLK, 0, 2,  SysClk(u8) {
+            /// The system clock runs from the crystal until the PLL is locked, and then automatically switches to the PLL.
+            Auto = 0b00,
+            /// Force the system clock to the FOSC clock divided by 4.
+            FoscDiv4 = 0b01,
+            /// Force the system clock to the 124.8 MHz PLL clock.
+            Pll = 0b10,
+            /// Force the system clock to the 38.4 MHz FOSC clock.
+            Fosc = 0b11,
+        };
         /// Receiver Clock Selection
         RX_CLK, 2, 2,  u8;
         /// Transmitter Clock Selection.
         TX_CLK, 4, 2,  u8;
         /// Force Accumulator Clock Enable
-        ACC_CLK_EN, 6, 1,  u8;
+        ACC_CLK_EN, 6, 1,  bool;
         /// Force CIA Clock Enable
-        CIA_CLK_EN, 8, 1,  u8;
+        CIA_CLK_EN, 8, 1,  bool;
         /// Analog-to-Digital Convertor Clock Enable.
-        SAR_CLK_EN, 10, 1,  u8;
+        SAR_CLK_EN, 10, 1,  bool;
         /// Accumulator Memory Clock Enable.
-        ACC_MCLK_EN, 15, 1,  u8;
+        ACC_MCLK_EN, 15, 1,  bool;
         /// GPIO clock Enable
-        GPIO_CLK_EN, 16, 1,  u8;
+        GPIO_CLK_EN, 16, 1,  bool;
         /// GPIO De-bounce Clock Enable.
-        GPIO_DCLK_EN, 18, 1,  u8;
+        GPIO_DCLK_EN, 18, 1,  bool;
         /// GPIO de-bounce reset (NOT), active low.
-        GPIO_DRST_N, 19, 1,  u8;
+        GPIO_DRST_N, 19, 1,  bool;
         /// Kilohertz clock Enable.
-        LP_CLK_EN, 23, 1,  u8;
+        LP_CLK_EN, 23, 1,  bool;
     }
     /// PMSC sequencing control register
-    [0x11, 0x08, 4, RW, SEQ_CTRL(seq_ctrl)] {
+    [0x11, 0x08, 4, RW, SEQ_CTRL(seq_ctrl), 0x0000_0138] {
         /// Automatic  IDLE_RC  to  IDLE_PLL.
-        AINIT2IDLE, 8, 1,  u8;
+        AINIT2IDLE, 8, 1,  bool;
         /// After TX automatically Sleep.
-        ATX2SLP, 11, 1,  u8;
+        ATX2SLP, 11, 1,  bool;
         /// After RX automatically Sleep.
-        ARX2SLP, 12, 1,  u8;
+        ARX2SLP, 12, 1,  bool;
         /// This enables a 1 GHz clock used for some external SYNC modes.
-        PLL_SYNC, 15, 1,  u8;
+        PLL_SYNC, 15, 1,  bool;
         /// CIA run enable.
-        CIARUNE, 17, 1,  u8;
+        CIARUNE, 17, 1,  bool;
         /// Force to IDLE_RC state.
-        FORCE2INIT, 23, 1,  u8;
+        FORCE2INIT, 23, 1,  bool;
         /// Kilohertz clock divisor.
         LP_CLK_DIV, 26, 6,  u8;
     }
     /// PMSC fine grain TX sequencing control
-    [0x11, 0x12, 4, RW, TXFSEQ(txfseq)] {
+    [0x11, 0x12, 4, RW, TXFSEQ(txfseq), ?] {
         /// PMSC fine grain TX sequencing control
         VALUE, 0, 32,  u32;
     }
     /// PMSC fine grain TX sequencing control
-    [0x11, 0x16, 4, RW, LED_CTRL(led_ctrl)] {
+    [0x11, 0x16, 4, RW, LED_CTRL(led_ctrl), 0x0000_0020] {
         /// Blink time count value.
         BLINK_TIM, 0, 8,  u8;
         /// Blink Enable.
-        BLINK_EN, 8, 1,  u8;
+        BLINK_EN, 8, 1,  bool;
         /// Manually triggers an LED blink.
         FORCE_TRIG, 16, 4,  u8;
     }
     /// Receiver SNIFF mode configuration
-    [0x11, 0x1A, 4, RW, RX_SNIFF(rx_sniff)] {
+    [0x11, 0x1A, 4, RW, RX_SNIFF(rx_sniff), 0x00] {
         /// SNIFF Mode ON time.
         SNIFF_ON, 0, 4,  u8;
         /// SNIFF Mode OFF time specified in μs.
         SNIFF_OFF, 8, 8,  u8;
     }
     /// Analog blocks’ calibration values
-    [0x11, 0x1F, 2, RW, BIAS_CTRL(bias_ctrl)] {
+    [0x11, 0x1F, 2, RW, BIAS_CTRL(bias_ctrl), ?] {
         /// Analog blocks’ calibration values
         VALUE, 0, 14,  u16;
     }
     ///
+    /// Receive Data Buffer 0
+    ///
+    /// Holds the data of a received frame. When double buffering is disabled via the [DIS_DRXB](sys_cfg::DIS_DRXB) bit of the
+    /// [SYS_CFG] register, this is the only receive buffer that is used.
+    ///
+    [0x12, 0x00, 1024, RO, RX_BUFFER_0(rx_buffer_0), ?] {
+    }
+    ///
+    /// Receive Data Buffer 1
+    ///
+    /// Holds the data of a received frame. This buffer is only used when double buffering is enabled via the
+    /// [DIS_DRXB](sys_cfg::DIS_DRXB) bit of the [SYS_CFG] register.
+    ///
+    [0x13, 0x00, 1024, RO, RX_BUFFER_1(rx_buffer_1), ?] {
+    }
+    ///
     /// Transmit Data Buffer
     ///
     /// <div class="warning">
     /// This register mapping and its contents have been hand verified to be correct by a real human.
     /// </div>
     ///
-    [0x14, 0x00, 1024, WO, TX_BUFFER(tx_buffer)] {
+    [0x14, 0x00, 1024, WO, TX_BUFFER(tx_buffer), ?] {
     }
     /// Read access to accumulator data memory
-    [0x15, 0x00, 12288, RO, ACC_MEM(acc_mem)] {
+    [0x15, 0x00, 12288, RO, ACC_MEM(acc_mem), ?] {
     }
     /// Scratch RAM memory buffer
-    [0x16, 0x00, 127, RW, SCRATCH_RAM(scratch_ram)] {
+    [0x16, 0x00, 127, RW, SCRATCH_RAM(scratch_ram), ?] {
     }
     /// storage for up to 8 x 128 bit AES KEYs
-    [0x17, 0x00, 128, RW, AES_KEY_RAM(aes_key_ram)] {
+    [0x17, 0x00, 128, RW, AES_KEY_RAM(aes_key_ram), ?] {
         /// 1st AES key
         AES_KEY1, 0, 128,  u128;
         /// 2nd AES key
@@ -2068,67 +2870,147 @@ impl_registers! {
         AES_KEY8, 896, 128,  u128;
     }
     /// Double buffer diagnostic register set
-    [0x18, 0x00, 464, RO, DB_DIAG(db_diag)] {
+    [0x18, 0x00, 464, RO, DB_DIAG(db_diag), ?] {
     }
     /// Double buffer diagnostic register set 1
-    [0x18, 0x00, 232, RO, DB_DIAG_SET1(db_diag_set1)] {
+    [0x18, 0x00, 232, RO, DB_DIAG_SET1(db_diag_set1), ?] {
     }
     /// Double buffer diagnostic register set 2
-    [0x18, 0xE8, 232, RO, DB_DIAG_SET2(db_diag_set2)] {
+    [0x18, 0xE8, 232, RO, DB_DIAG_SET2(db_diag_set2), ?] {
     }
     /// Indirect pointer A
-    [0x1D, 0x00, 1, RW, INDIRECT_PTR_A(indirect_ptr_a)] {
+    [0x1D, 0x00, 1, RW, INDIRECT_PTR_A(indirect_ptr_a), ?] {
         /// Indirect pointer A
         VALUE, 0, 8,  u8;
     }
     /// Indirect pointer B
-    [0x1E, 0x00, 1, RW, INDIRECT_PTR_B(indirect_ptr_b)] {
+    [0x1E, 0x00, 1, RW, INDIRECT_PTR_B(indirect_ptr_b), ?] {
         /// Indirect pointer B
         VALUE, 0, 8,  u8;
     }
     /// Fast System Event Status Register
-    [0x1F, 0x00, 1, RO, FINT_STAT(fint_stat)] {
+    [0x1F, 0x00, 1, RO, FINT_STAT(fint_stat), ?] {
         /// TXFRB or TXPRS or TXPHS or TXFRS.
-        TXOK, 0, 1,   u8;
+        TXOK, 0, 1,   bool;
         /// AAT or CCA_FAIL.
-        CCA_FAIL, 1, 1,   u8;
+        CCA_FAIL, 1, 1,   bool;
         /// CIAERR
-        RXTSERR, 2, 1,   u8;
+        RXTSERR, 2, 1,   bool;
         /// RXFR and CIADONE or RXFCG.
-        RXOK, 3, 1,   u8;
+        RXOK, 3, 1,   bool;
         /// RXFCE or RXFSL or  RXPHE or  ARFE or  RXSTO or RXOVRR.
-        RXERR, 4, 1,   u8;
+        RXERR, 4, 1,   bool;
         /// RXFTO  or  RXPTO.
-        RXTO, 5, 1,   u8;
+        RXTO, 5, 1,   bool;
         /// VT_DET or GPIOIRQ or RCINIT or SPIRDY.
-        SYS_EVENT, 6, 1,   u8;
+        SYS_EVENT, 6, 1,   bool;
         /// AES_ERR or CMD_ERR or SPI_UNF or SPI_OVF or SPIERR or PLL_HILO or VWARN.
-        SYS_PANIC, 7, 1,   u8;
+        SYS_PANIC, 7, 1,   bool;
     }
     /// Base address of the register to be accessed through indirect pointer A
-    [0x1F, 0x04, 1, RW, PTR_ADDR_A(ptr_addr_a)] {
+    [0x1F, 0x04, 1, RW, PTR_ADDR_A(ptr_addr_a), 0x00] {
         /// Base address of the register to be accessed through indirect pointer A
         PTRA_BASE, 0, 5,   u8;
     }
     /// Offset address of the register to be accessed through indirect pointer A
-    [0x1F, 0x08, 2, RW, PTR_OFFSET_A(ptr_offset_a)] {
+    [0x1F, 0x08, 2, RW, PTR_OFFSET_A(ptr_offset_a), 0x00] {
         /// Offset address of the register to be accessed through indirect pointer A
         PTRA_OFS, 0, 15,   u16;
     }
     /// Base address of the register to be accessed through indirect pointer B
-    [0x1F, 0x0C, 1, RW, PTR_ADDR_B(ptr_addr_b)] {
+    [0x1F, 0x0C, 1, RW, PTR_ADDR_B(ptr_addr_b), 0x00] {
         /// Base address of the register to be accessed through indirect pointer B
         PTRB_BASE, 0, 5,   u8;
     }
     /// Offset address of the register to be accessed through indirect pointer B
-    [0x1F, 0x10, 2, RW, PTR_OFFSET_B(ptr_offset_b)] {
+    [0x1F, 0x10, 2, RW, PTR_OFFSET_B(ptr_offset_b), 0x00] {
         /// Offset address of the register to be accessed through indirect pointer B
         PTRB_OFS, 0, 15,   u16;
     }
 }
 
-impl TX_BUFFER {
-    pub fn write(&mut self) {
-        todo!()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_crossing_byte_boundaries() {
+        let mut register = [0x00; 6];
+
+        tx_fctrl::TXFLEN::write(&mut register, 0x3FF);
+        assert_eq!(register, [0xFF, 0x03, 0x00, 0x00, 0x00, 0x00]);
+
+        tx_fctrl::TXB_OFFSET::write(&mut register, 0x2A5);
+        assert_eq!(register, [0xFF, 0x03, 0xA5, 0x02, 0x00, 0x00]);
+
+        assert_eq!(tx_fctrl::TXFLEN::read(&register), 0x3FF);
+        assert_eq!(tx_fctrl::TXB_OFFSET::read(&register), 0x2A5);
+    }
+
+    #[test]
+    fn fields_of_128_bits() {
+        let key = 0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF;
+        let mut register = [0x00; 16];
+
+        aes_key::VALUE::write(&mut register, key);
+        assert_eq!(register, key.to_le_bytes());
+        assert_eq!(aes_key::VALUE::read(&register), key);
+
+        aes_key::VALUE::write(&mut register, u128::MAX);
+        assert_eq!(register, [0xFF; 16]);
+
+        let reset = STS_KEY::RESET_VALUE.unwrap();
+        assert_eq!(sts_key::VALUE::read(&reset), 0x1414_8674_D1D3_36AA_F860_50A8_14EB_220F);
+    }
+
+    #[test]
+    fn top_bit_of_odd_sized_registers() {
+        let mut register = [0x00; 3];
+
+        rx_fwto::VALUE::write(&mut register, 0x80_0000);
+        assert_eq!(register, [0x00, 0x00, 0x80]);
+        assert_eq!(rx_fwto::VALUE::read(&register), 0x80_0000);
+
+        // Bits of the value beyond the field are discarded.
+        rx_fwto::VALUE::write(&mut register, 0x1FF_FFFF);
+        assert_eq!(register, [0xFF, 0xFF, 0xFF]);
+
+        assert_eq!(tx_time::TX_STAMP::read(&[0x00, 0x00, 0x00, 0x00, 0x80]), 1 << 39);
+        assert_eq!(tx_time::TX_STAMP::read(&[0xFF; 5]), 0xFF_FFFF_FFFF);
+    }
+
+    #[test]
+    fn write_then_read_preserves_neighbouring_bits() {
+        let mut register = [0xA5, 0x5A, 0xC3, 0x3C, 0x96, 0x69];
+
+        tx_fctrl::TXPSR::write(&mut register, PreambleLength::Symbols4096);
+        assert_eq!(register, [0xA5, 0x3A, 0xC3, 0x3C, 0x96, 0x69]);
+        assert_eq!(tx_fctrl::TXPSR::read(&register), Ok(PreambleLength::Symbols4096));
+
+        tx_fctrl::TXB_OFFSET::write(&mut register, 0x155);
+        assert_eq!(register, [0xA5, 0x3A, 0x55, 0x3D, 0x96, 0x69]);
+        assert_eq!(tx_fctrl::TXB_OFFSET::read(&register), 0x155);
+
+        // The fields either side of those written keep their values.
+        assert_eq!(tx_fctrl::TXFLEN::read(&register), 0x2A5);
+        assert_eq!(tx_fctrl::TXBR::read(&register), Ok(DataRate::Rate850K));
+        assert!(tx_fctrl::TR::read(&register));
+        assert_eq!(tx_fctrl::FINE_PLEN::read(&register), 0x69);
+    }
+
+    #[test]
+    fn pin_specific_gpio_modes() {
+        let mut register = [0x00; 4];
+
+        assert_eq!(gpio_mode::MSGP7::read(&register), Ok(Gpio7Mode::Sync));
+        assert_eq!(gpio_mode::MSGP8::read(&register), Ok(Gpio8Mode::Irq));
+
+        gpio_mode::MSGP0::write(&mut register, Gpio0Mode::PdoaSwitch);
+        gpio_mode::MSGP7::write(&mut register, Gpio7Mode::Gpio);
+        gpio_mode::MSGP8::write(&mut register, Gpio8Mode::Gpio);
+        assert_eq!(register, [0x02, 0x00, 0x20, 0x01]);
+
+        assert_eq!(gpio_mode::MSGP0::read(&register), Ok(Gpio0Mode::PdoaSwitch));
+        assert_eq!(gpio_mode::MSGP6::read(&[0x00, 0x00, 0x18, 0x00]), Err(ReservedValue(0b110)));
     }
 }
@@ -2,7 +2,114 @@
 //! 
 //! # Transaction Helper Functions
 //! 
-//! This module includes a series of helper functions for performing SPI transactions with the device.
+//! This module includes a series of helper functions for performing SPI transactions with the device using an
+//! [`embedded_hal::spi::SpiDevice`]. Each function sends the transaction header followed by the transaction data within a single
+//! assertion of the chip select line, and returns any error from the underlying bus.
+//! 
+//! For each of the four main transaction types there is a corresponding function ([`fast_command`], [`short_addressed`],
+//! [`full_addressed`], and [`masked_write`]), and additionally there are the typed functions [`read()`], [`write()`], and [`modify()`]
+//! for accessing entire registers from the [`reg`](crate::ll::reg) module.
+//! 
+//! ## Masked Writes
+//! 
+//! A single field may be written without a read-modify-write sequence using [`modify_field`], which issues a masked write
+//! transaction with the smallest [`MaskedWriteMode`] covering the field. The device applies the AND and OR masks itself, so the
+//! bits surrounding the field are never read back and overwritten by the host.
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! # use dw3xxx::ll::{spi::{self, MaskedWriteMode}, reg::{tx_fctrl, PreambleLength}};
+//! # let mut spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(spi::masked_write_header(0x00, 0x25, MaskedWriteMode::EightBit).to_vec()),
+//! #     Transaction::write_vec(vec![0x0F, 0x10]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! // TXPSR occupies bits 12 to 15 of TX_FCTRL, so only the second octet of the register is masked.
+//! spi::modify_field::<tx_fctrl::TXPSR, _>(&mut spi, PreambleLength::Symbols64)?;
+//! # spi.done();
+//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! ```
+//! 
+//! ## Indirect Access
+//! 
+//! Full addressed transactions may only address the first 128 octets of a register file, so larger register files such as
+//! [`ACC_MEM`](crate::ll::reg::ACC_MEM) and [`TX_BUFFER`](crate::ll::reg::TX_BUFFER) are accessed beyond that point using one of
+//! the two indirect pointers with [`indirect`]. The pointer's base address and offset are checked and held by an [`IndirectAccess`],
+//! and are programmed first, after which the data is streamed through the pointer's own register file ([`INDIRECT_PTR_A`] or
+//! [`INDIRECT_PTR_B`]).
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! # use dw3xxx::ll::{spi::{self, AccessMode, IndirectAccess, IndirectPointer}, reg::ACC_MEM};
+//! # let mut spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(spi::full_addressed_header(0x1F, 0x04, AccessMode::Write).to_vec()),
+//! #     Transaction::write_vec(vec![0x15]),
+//! #     Transaction::transaction_end(),
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(spi::full_addressed_header(0x1F, 0x08, AccessMode::Write).to_vec()),
+//! #     Transaction::write_vec(vec![0x00, 0x10]),
+//! #     Transaction::transaction_end(),
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(spi::short_addressed_header(0x1D, AccessMode::Read).to_vec()),
+//! #     Transaction::read_vec(vec![0x01, 0x02, 0x03, 0x04]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! // Read four octets of the accumulator memory at offset 4096 through indirect pointer A.
+//! let mut data = [0; 4];
+//! let access = IndirectAccess::for_register::<ACC_MEM>(IndirectPointer::A, 4096, data.len()).unwrap();
+//! spi::indirect(&mut spi, access, AccessMode::Read, &mut data)?;
+//! # assert_eq!(data, [0x01, 0x02, 0x03, 0x04]);
+//! # spi.done();
+//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! ```
+//! 
+//! ## SPI CRC
+//! 
+//! When the SPI CRC functionality of the device is enabled (see [`SPI_CRCEN`](crate::ll::reg::sys_cfg::SPI_CRCEN)) each write
+//! transaction must be followed by a CRC-8 of the header and data, which may be done using the [`short_addressed_crc`],
+//! [`full_addressed_crc`], [`masked_write_crc`], [`read_crc`], [`write_crc`], and [`indirect_crc`] functions. With
+//! [`CrcMode::WriteRead`] these functions additionally check the data of each read transaction against the [`SPI_RD_CRC`] register
+//! and return [`TransactionError::CrcError`] on a mismatch.
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! # use dw3xxx::ll::spi::{self, AccessMode, CrcMode};
+//! # let mut spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b10001000]),
+//! #     Transaction::write_vec(vec![0x78, 0x56, 0x34, 0x12]),
+//! #     Transaction::write_vec(vec![spi::crc8(0, &[0b10001000, 0x78, 0x56, 0x34, 0x12])]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! // Write the PAN ID and short address followed by the CRC-8 of the transaction.
+//! spi::short_addressed_crc(&mut spi, 0x04, AccessMode::Write, CrcMode::Write, &mut [0x78, 0x56, 0x34, 0x12])?;
+//! # spi.done();
+//! # Ok::<(), spi::TransactionError<embedded_hal::spi::ErrorKind>>(())
+//! ```
+//! 
+//! With the `async` feature enabled the same functions are additionally available as async functions built on
+//! `embedded_hal_async::spi::SpiDevice` in the `asynch` submodule.
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! # use dw3xxx::ll::{spi, reg::SYS_CFG};
+//! # let mut spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b01000000, 0b01000000]),
+//! #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+//! #     Transaction::transaction_end(),
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b11000000, 0b01000000]),
+//! #     Transaction::write_vec(vec![0x89, 0x0E, 0x00, 0x00]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! // Enable frame filtering and automatic acknowledgement with a single read-modify-write of SYS_CFG.
+//! spi::modify::<SYS_CFG, _>(&mut spi, |r| r.set_ffen(true).set_auto_ack(true))?;
+//! # spi.done();
+//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! ```
 //! 
 //! # Header Helper Functions
 //! 
@@ -14,28 +121,28 @@
 //! or the variable transaction data that must be read or written immediately following the headers.
 //! 
 //! ## Fast Command
-//! To generate a header for a fast command transaction you may use the helper function [`fast_command`] as follows:
+//! To generate a header for a fast command transaction you may use the helper function [`fast_command_header`] as follows:
 //! ```rust
 //! # use dw3xxx::ll::{spi::fast_command_header, commands::Command};
 //! let header: [u8; 1] = fast_command_header(Command::ClrIrqs);
 //! ```
 //! 
 //! ## Short Addressed Transaction
-//! To generate a header for a short addressed transaction you may use the helper function [`short_addressed`] as follows:
+//! To generate a header for a short addressed transaction you may use the helper function [`short_addressed_header`] as follows:
 //! ```rust
 //! # use dw3xxx::ll::spi::{short_addressed_header, AccessMode};
 //! let header: [u8; 1] = short_addressed_header(0x12, AccessMode::Read);
 //! ```
 //! 
 //! ## Full Addressed Transaction
-//! To generate a header for a full addressed transaction you may use the helper function [`full_addressed`] as follows:
+//! To generate a header for a full addressed transaction you may use the helper function [`full_addressed_header`] as follows:
 //! ```rust
 //! # use dw3xxx::ll::spi::{full_addressed_header, AccessMode};
 //! let header: [u8; 2] = full_addressed_header(0x05, 0x2C, AccessMode::Read);
 //! ```
 //! 
 //! ## Masked Write Transaction
-//! To generate a header for a masked write transaction you may use the helper function [`masked_write`] as follows:
+//! To generate a header for a masked write transaction you may use the helper function [`masked_write_header`] as follows:
 //! ```rust
 //! # use dw3xxx::ll::spi::{masked_write_header, MaskedWriteMode};
 //! let header: [u8; 2] = masked_write_header(0x05, 0x10, MaskedWriteMode::EightBit);
@@ -59,28 +166,549 @@
 //! clearing a bit range and then writing to that bit range immediately after.
 //! 
 
+#[cfg(feature = "async")]
+pub mod asynch;
+
+use embedded_hal::spi::{Operation, SpiDevice};
+
 use crate::ll::commands::Command;
+use crate::ll::reg::{
+    FieldValue, Register, Writable, SPI_RD_CRC, write_bits,
+    INDIRECT_PTR_A, INDIRECT_PTR_B, PTR_ADDR_A, PTR_ADDR_B, PTR_OFFSET_A, PTR_OFFSET_B
+};
 
 /// Execute a fast command
-pub fn fast_command() {
-    todo!()
+pub fn fast_command<SPI: SpiDevice>(spi: &mut SPI, command: Command) -> Result<(), SPI::Error> {
+    spi.write(&fast_command_header(command))
 }
 
+/// 
 /// Perform a short addressed SPI transaction.
-pub fn short_addressed() {
-    todo!()
+/// 
+/// When the access mode is [`AccessMode::Read`] the data buffer is filled with the data read from the device, and when the access
+/// mode is [`AccessMode::Write`] the contents of the data buffer are written to the device.
+/// 
+pub fn short_addressed<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    let header = short_addressed_header(base_address, mode);
+
+    transfer(spi, &header, mode, data)
 }
 
+/// 
 /// Perform a full addressed SPI transaction.
-pub fn full_addressed() {
-    todo!()
+/// 
+/// When the access mode is [`AccessMode::Read`] the data buffer is filled with the data read from the device, and when the access
+/// mode is [`AccessMode::Write`] the contents of the data buffer are written to the device.
+/// 
+pub fn full_addressed<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, sub_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    let header = full_addressed_header(base_address, sub_address, mode);
+
+    transfer(spi, &header, mode, data)
 }
 
+/// 
 /// Perform a masked write SPI transaction.
-pub fn masked_write() {
-    todo!()
+/// 
+/// The data must consist of the AND mask immediately followed by the OR mask, each of which are the length specified by the
+/// [`MaskedWriteMode`] (e.g. two octets in total for [`MaskedWriteMode::EightBit`]). The device first performs a bitwise AND of the
+/// register with the AND mask, and then a bitwise OR with the OR mask.
+/// 
+pub fn masked_write<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, sub_address: u8, mode: MaskedWriteMode, data: &[u8]) -> Result<(), SPI::Error> {
+    debug_assert!(
+        mode == MaskedWriteMode::Unmasked || data.len() == 2 * mode.mask_len(),
+        "the masked write data must contain exactly one AND mask and one OR mask"
+    );
+
+    let header = masked_write_header(base_address, sub_address, mode);
+
+    spi.transaction(&mut [Operation::Write(&header), Operation::Write(data)])
 }
 
+/// 
+/// Read an entire register from the device.
+/// 
+/// Registers with a sub-address of zero are read with a short addressed transaction, and all others with a full addressed
+/// transaction.
+/// 
+pub fn read<R: Register, SPI: SpiDevice>(spi: &mut SPI) -> Result<R::RegisterValue, SPI::Error> {
+    without_crc(read_crc::<R, SPI>(spi, CrcMode::Disabled))
+}
+
+/// 
+/// Write an entire register to the device.
+/// 
+/// Registers with a sub-address of zero are written with a short addressed transaction, and all others with a full addressed
+/// transaction.
+/// 
+pub fn write<R: Register, SPI: SpiDevice>(spi: &mut SPI, value: R::RegisterValue) -> Result<(), SPI::Error> {
+    without_crc(write_crc::<R, SPI>(spi, CrcMode::Disabled, value))
+}
+
+/// 
+/// Modify a register on the device.
+/// 
+/// The register is read once, modified by the closure, and then written back once, such that any number of fields may be changed
+/// with a single read-modify-write of the register.
+/// 
+pub fn modify<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    f: impl FnOnce(&mut R::RegisterValue) -> &mut R::RegisterValue,
+) -> Result<(), SPI::Error> {
+    let mut value = read::<R, SPI>(spi)?;
+
+    f(&mut value);
+
+    write::<R, SPI>(spi, value)
+}
+
+/// 
+/// Write a single field of a register on the device with a masked write transaction.
+/// 
+/// See [`MaskedFieldWrite`] for how the masked write transaction is chosen.
+/// 
+pub fn modify_field<F: Writable, SPI: SpiDevice>(spi: &mut SPI, value: F::Value) -> Result<(), SPI::Error> {
+    let write = MaskedFieldWrite::new::<F>(value);
+
+    masked_write(spi, F::Register::BASE_ADDRESS, write.sub_address(), write.mode(), write.masks())
+}
+
+/// 
+/// A masked write transaction that writes a single field of a register.
+/// 
+/// The transaction uses the smallest [`MaskedWriteMode`] whose masks cover every octet of the field, with the sub-address of the
+/// transaction pointing at the first octet covered by the masks. When the masks would extend beyond the end of the register they
+/// are instead aligned to the end of the register, such that only the register itself is ever written.
+/// 
+/// Fields spanning more than four octets, fields whose masks can not fit within the register, and fields whose masks begin at a
+/// sub-address larger than [`MAX_SUB_ADDRESS`] are rejected at compile time.
+/// 
+/// ```rust
+/// # use dw3xxx::ll::{spi::{MaskedFieldWrite, MaskedWriteMode}, reg::tx_fctrl};
+/// // TXFLEN occupies bits 0 to 9 of TX_FCTRL at sub-address 0x24.
+/// let write = MaskedFieldWrite::new::<tx_fctrl::TXFLEN>(0x07F);
+/// assert_eq!(write.sub_address(), 0x24);
+/// assert_eq!(write.mode(), MaskedWriteMode::SixteenBit);
+/// assert_eq!(write.masks(), [0x00, 0xFC, 0x7F, 0x00]);
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaskedFieldWrite {
+    sub_address: u8,
+    mode: MaskedWriteMode,
+    masks: [u8; 8]
+}
+
+impl MaskedFieldWrite {
+    /// Constructs the masked write transaction for writing the value to the field.
+    pub fn new<F: Writable>(value: F::Value) -> Self {
+        let (first_octet, mode) = const {
+            let first_octet = F::FIRST_BIT / 8;
+            let last_octet = (F::FIRST_BIT + F::SIZE as usize - 1) / 8;
+
+            let mode = match last_octet - first_octet {
+                0 => MaskedWriteMode::EightBit,
+                1 => MaskedWriteMode::SixteenBit,
+                2 | 3 => MaskedWriteMode::ThirtyTwoBit,
+                _ => panic!("the field is too large for a masked write transaction"),
+            };
+
+            let first_octet = match first_octet + mode.mask_len() > F::Register::LEN {
+                true  => F::Register::LEN.saturating_sub(mode.mask_len()),
+                false => first_octet,
+            };
+
+            assert!(first_octet + mode.mask_len() <= F::Register::LEN, "the masked write transaction does not fit within the register");
+            assert!(
+                F::Register::SUB_ADDRESS as usize + first_octet <= MAX_SUB_ADDRESS as usize,
+                "the field's sub-address is too large for a masked write transaction"
+            );
+
+            (first_octet, mode)
+        };
+
+        let len = mode.mask_len();
+        let first_bit = F::FIRST_BIT - 8 * first_octet;
+
+        let mut and_mask = [0xFF; 4];
+        let mut or_mask = [0x00; 4];
+        write_bits(&mut and_mask, first_bit, F::SIZE, 0);
+        write_bits(&mut or_mask, first_bit, F::SIZE, value.into_bits());
+
+        let mut masks = [0; 8];
+        masks[..len].copy_from_slice(&and_mask[..len]);
+        masks[len..2 * len].copy_from_slice(&or_mask[..len]);
+
+        Self { sub_address: F::Register::SUB_ADDRESS + first_octet as u8, mode, masks }
+    }
+
+    /// The sub-address of the first octet covered by the masks.
+    pub fn sub_address(&self) -> u8 {
+        self.sub_address
+    }
+
+    /// The write length mode of the transaction.
+    pub fn mode(&self) -> MaskedWriteMode {
+        self.mode
+    }
+
+    /// The AND mask immediately followed by the OR mask.
+    pub fn masks(&self) -> &[u8] {
+        &self.masks[..2 * self.mode.mask_len()]
+    }
+}
+
+/// 
+/// Perform an SPI transaction through one of the indirect pointers.
+/// 
+/// The pointer is first programmed with the base address of the register file and the offset of the first octet within the register
+/// file, after which the data is read from or written to the pointer's register file. See [`IndirectAccess`].
+/// 
+pub fn indirect<SPI: SpiDevice>(spi: &mut SPI, access: IndirectAccess, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    without_crc(indirect_crc(spi, access, mode, CrcMode::Disabled, data))
+}
+
+/// 
+/// An access through one of the indirect pointers at an offset within a register file.
+/// 
+/// Unlike full addressed transactions, the offset is not limited to 7 bits. The base address may be at most
+/// [`MAX_INDIRECT_BASE_ADDRESS`] and the offset at most [`MAX_INDIRECT_OFFSET`], which is checked when the access is constructed
+/// such that every access passed to [`indirect`] can be programmed into the pointer.
+/// 
+/// ```rust
+/// # use dw3xxx::ll::{spi::{IndirectAccess, IndirectPointer}, reg::TX_BUFFER};
+/// // Write 16 octets of the transmit buffer from offset 512.
+/// let access = IndirectAccess::for_register::<TX_BUFFER>(IndirectPointer::A, 512, 16);
+/// assert_eq!(access.map(|access| access.offset()), Some(512));
+/// 
+/// // The transmit buffer is only 1024 octets long.
+/// assert_eq!(IndirectAccess::for_register::<TX_BUFFER>(IndirectPointer::A, 1020, 16), None);
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndirectAccess {
+    pointer: IndirectPointer,
+    base_address: u8,
+    offset: u16
+}
+
+impl IndirectAccess {
+    /// Constructs an access at the offset within the register file, or returns [`None`] if either is too large for the pointer.
+    pub const fn new(pointer: IndirectPointer, base_address: u8, offset: usize) -> Option<Self> {
+        if base_address > MAX_INDIRECT_BASE_ADDRESS || offset > MAX_INDIRECT_OFFSET as usize {
+            return None;
+        }
+
+        Some(Self { pointer, base_address, offset: offset as u16 })
+    }
+
+    /// 
+    /// Constructs an access of `len` octets at the offset into a register, or returns [`None`] if the access would extend beyond the
+    /// end of the register or is too large for the pointer.
+    /// 
+    pub const fn for_register<R: Register>(pointer: IndirectPointer, offset: usize, len: usize) -> Option<Self> {
+        match offset.checked_add(len) {
+            Some(end) if end <= R::LEN => Self::new(pointer, R::BASE_ADDRESS, R::SUB_ADDRESS as usize + offset),
+            _                          => None,
+        }
+    }
+
+    /// The indirect pointer used for the access.
+    pub const fn pointer(&self) -> IndirectPointer {
+        self.pointer
+    }
+
+    /// The base address of the register file programmed into the pointer.
+    pub const fn base_address(&self) -> u8 {
+        self.base_address
+    }
+
+    /// The offset of the first octet within the register file programmed into the pointer.
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+}
+
+/// The largest base address that can be programmed into an indirect pointer.
+pub const MAX_INDIRECT_BASE_ADDRESS: u8 = 0x1F;
+
+/// The largest offset that can be programmed into an indirect pointer.
+pub const MAX_INDIRECT_OFFSET: u16 = 0x7FFF;
+
+/// One of the two indirect pointers of the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndirectPointer {
+    /// Indirect pointer A, accessed through [`INDIRECT_PTR_A`].
+    A,
+    /// Indirect pointer B, accessed through [`INDIRECT_PTR_B`].
+    B
+}
+
+impl IndirectPointer {
+    /// The base address of the register file through which data is accessed.
+    pub const fn data_register(&self) -> u8 {
+        match self {
+            IndirectPointer::A => INDIRECT_PTR_A::BASE_ADDRESS,
+            IndirectPointer::B => INDIRECT_PTR_B::BASE_ADDRESS,
+        }
+    }
+
+    /// The base address and sub-address of the register holding the pointer's target base address.
+    pub const fn base_address_register(&self) -> (u8, u8) {
+        match self {
+            IndirectPointer::A => (PTR_ADDR_A::BASE_ADDRESS, PTR_ADDR_A::SUB_ADDRESS),
+            IndirectPointer::B => (PTR_ADDR_B::BASE_ADDRESS, PTR_ADDR_B::SUB_ADDRESS),
+        }
+    }
+
+    /// The base address and sub-address of the register holding the pointer's target offset.
+    pub const fn offset_register(&self) -> (u8, u8) {
+        match self {
+            IndirectPointer::A => (PTR_OFFSET_A::BASE_ADDRESS, PTR_OFFSET_A::SUB_ADDRESS),
+            IndirectPointer::B => (PTR_OFFSET_B::BASE_ADDRESS, PTR_OFFSET_B::SUB_ADDRESS),
+        }
+    }
+}
+
+/// Sends a transaction header followed by the transaction data in the direction given by the access mode.
+fn transfer<SPI: SpiDevice>(spi: &mut SPI, header: &[u8], mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    match mode {
+        AccessMode::Read  => spi.transaction(&mut [Operation::Write(header), Operation::Read(data)]),
+        AccessMode::Write => spi.transaction(&mut [Operation::Write(header), Operation::Write(data)]),
+    }
+}
+
+/// Performs a short or full addressed transaction on an entire register depending on its sub-address.
+fn register_transfer_crc<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    const {
+        assert!(R::SUB_ADDRESS <= MAX_SUB_ADDRESS, "the register's sub-address is too large for a full addressed transaction");
+    }
+
+    match R::SUB_ADDRESS {
+        0 => short_addressed_crc(spi, R::BASE_ADDRESS, mode, crc, data),
+        _ => full_addressed_crc(spi, R::BASE_ADDRESS, R::SUB_ADDRESS, mode, crc, data),
+    }
+}
+
+/// Unwraps the result of a transaction performed with [`CrcMode::Disabled`], which never checks the CRC.
+pub(super) fn without_crc<T, E>(result: Result<T, TransactionError<E>>) -> Result<T, E> {
+    result.map_err(|error| match error {
+        TransactionError::BusError(error) => error,
+        TransactionError::CrcError        => unreachable!("the CRC is not checked when SPI CRC is disabled"),
+    })
+}
+
+/// 
+/// Perform a short addressed SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`short_addressed`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn short_addressed_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let header = short_addressed_header(base_address, mode);
+
+    transfer_crc(spi, &header, mode, crc, data)
+}
+
+/// 
+/// Perform a full addressed SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`full_addressed`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn full_addressed_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    sub_address: u8,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let header = full_addressed_header(base_address, sub_address, mode);
+
+    transfer_crc(spi, &header, mode, crc, data)
+}
+
+/// 
+/// Perform a masked write SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`masked_write`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn masked_write_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    sub_address: u8,
+    mode: MaskedWriteMode,
+    crc: CrcMode,
+    data: &[u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    if crc == CrcMode::Disabled {
+        return masked_write(spi, base_address, sub_address, mode, data).map_err(TransactionError::BusError);
+    }
+
+    debug_assert!(
+        mode == MaskedWriteMode::Unmasked || data.len() == 2 * mode.mask_len(),
+        "the masked write data must contain exactly one AND mask and one OR mask"
+    );
+
+    let header = masked_write_header(base_address, sub_address, mode);
+    let checksum = [crc8(crc8(0, &header), data)];
+
+    spi.transaction(&mut [Operation::Write(&header), Operation::Write(data), Operation::Write(&checksum)])
+        .map_err(TransactionError::BusError)
+}
+
+/// 
+/// Read an entire register from the device with the given SPI CRC mode.
+/// 
+/// See [`read`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn read_crc<R: Register, SPI: SpiDevice>(spi: &mut SPI, crc: CrcMode) -> Result<R::RegisterValue, TransactionError<SPI::Error>> {
+    let mut value = R::RegisterValue::from(R::ZEROED);
+
+    register_transfer_crc::<R, SPI>(spi, AccessMode::Read, crc, value.as_mut())?;
+
+    Ok(value)
+}
+
+/// 
+/// Write an entire register to the device with the given SPI CRC mode.
+/// 
+/// See [`write()`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn write_crc<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    crc: CrcMode,
+    mut value: R::RegisterValue,
+) -> Result<(), TransactionError<SPI::Error>> {
+    register_transfer_crc::<R, SPI>(spi, AccessMode::Write, crc, value.as_mut())
+}
+
+/// 
+/// Perform an SPI transaction through one of the indirect pointers with the given SPI CRC mode.
+/// 
+/// See [`indirect`] and the [SPI CRC](self#spi-crc) section of the module documentation.
+/// 
+pub fn indirect_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    access: IndirectAccess,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let (base, sub) = access.pointer().base_address_register();
+    full_addressed_crc(spi, base, sub, AccessMode::Write, crc, &mut [access.base_address()])?;
+
+    let (base, sub) = access.pointer().offset_register();
+    full_addressed_crc(spi, base, sub, AccessMode::Write, crc, &mut access.offset().to_le_bytes())?;
+
+    short_addressed_crc(spi, access.pointer().data_register(), mode, crc, data)
+}
+
+/// Performs a transfer with the given SPI CRC mode, appending the CRC-8 to writes and checking reads against `SPI_RD_CRC`.
+fn transfer_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    header: &[u8],
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    match (mode, crc) {
+        (_, CrcMode::Disabled) | (AccessMode::Read, CrcMode::Write) => {
+            transfer(spi, header, mode, data).map_err(TransactionError::BusError)
+        },
+        (AccessMode::Write, _) => {
+            let checksum = [crc8(crc8(0, header), data)];
+
+            spi.transaction(&mut [Operation::Write(header), Operation::Write(data), Operation::Write(&checksum)])
+                .map_err(TransactionError::BusError)
+        },
+        (AccessMode::Read, CrcMode::WriteRead) => {
+            transfer(spi, header, mode, data).map_err(TransactionError::BusError)?;
+
+            let mut checksum = [0];
+            full_addressed(spi, SPI_RD_CRC::BASE_ADDRESS, SPI_RD_CRC::SUB_ADDRESS, AccessMode::Read, &mut checksum)
+                .map_err(TransactionError::BusError)?;
+
+            match checksum[0] == crc8(crc8(0, header), data) {
+                true  => Ok(()),
+                false => Err(TransactionError::CrcError)
+            }
+        },
+    }
+}
+
+/// 
+/// Computes the SPI CRC-8 of the data, continuing from an initial CRC value.
+/// 
+/// The device uses the polynomial x<sup>8</sup> + x<sup>2</sup> + x + 1 with an initial value of zero, computed over the transaction
+/// header followed by the transaction data.
+/// 
+/// ```rust
+/// # use dw3xxx::ll::spi::crc8;
+/// assert_eq!(crc8(0, b"123456789"), 0xF4);
+/// assert_eq!(crc8(crc8(0, b"1234"), b"56789"), 0xF4);
+/// ```
+/// 
+pub const fn crc8(init: u8, data: &[u8]) -> u8 {
+    let mut crc = init;
+    let mut i = 0;
+
+    while i < data.len() {
+        crc ^= data[i];
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 0x80 {
+                0 => crc << 1,
+                _ => (crc << 1) ^ CRC8_POLYNOMIAL,
+            };
+            bit += 1;
+        }
+
+        i += 1;
+    }
+
+    crc
+}
+
+/// The SPI CRC-8 polynomial x<sup>8</sup> + x<sup>2</sup> + x + 1, with the implicit x<sup>8</sup> term omitted.
+const CRC8_POLYNOMIAL: u8 = 0x07;
+
+/// The SPI CRC mode used for transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrcMode {
+    /// No CRC is sent or checked.
+    #[default]
+    Disabled,
+    /// A CRC-8 is appended to every write transaction, which is checked by the device and reported with the
+    /// [`SPICRCE`](crate::ll::reg::sys_status::SPICRCE) event.
+    Write,
+    /// In addition to the CRC-8 appended to every write transaction, the data of every read transaction is checked against
+    /// the [`SPI_RD_CRC`] register.
+    WriteRead,
+}
+
+/// An error resulting from an SPI transaction with SPI CRC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionError<E> {
+    /// An error from the underlying SPI bus.
+    BusError(E),
+    /// The data of a read transaction did not match the [`SPI_RD_CRC`] register.
+    CrcError,
+}
+
+/// The largest sub-address that can be encoded in the 7-bit sub-address of a full addressed transaction header.
+pub const MAX_SUB_ADDRESS: u8 = 0x7F;
+
 /// 
 /// Generates a header for a fast command SPI transaction.
 /// 
@@ -211,12 +839,14 @@ pub fn masked_write_header(base_address: u8, sub_address: u8, mode: MaskedWriteM
 }
 
 /// The access mode of a short addressed or full addressed SPI transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AccessMode {
     Read  = 0b0,
     Write = 0b1,
 }
 
 /// The write length mode for the masked write SPI transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MaskedWriteMode {
     Unmasked     = 0b00,
     EightBit     = 0b01,
@@ -224,6 +854,18 @@ pub enum MaskedWriteMode {
     ThirtyTwoBit = 0b11
 }
 
+impl MaskedWriteMode {
+    /// The length in octets of each of the AND and OR masks for the mode, which is zero for [`MaskedWriteMode::Unmasked`].
+    pub const fn mask_len(&self) -> usize {
+        match self {
+            MaskedWriteMode::Unmasked     => 0,
+            MaskedWriteMode::EightBit     => 1,
+            MaskedWriteMode::SixteenBit   => 2,
+            MaskedWriteMode::ThirtyTwoBit => 4,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TransactionHeaderBuilder<const N: usize> {
     inner: [u8; N]
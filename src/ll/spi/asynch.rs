@@ -0,0 +1,300 @@
+//! 
+//! Asynchronous SPI transaction helper functions.
+//! 
+//! This module mirrors the transaction helper functions of the parent [`spi`](super) module, but is built on
+//! [`embedded_hal_async::spi::SpiDevice`] such that transactions may be awaited from an async executor (e.g. Embassy or RTIC 2)
+//! instead of blocking the CPU, which is particularly useful for large transfers to or from the TX and RX buffers.
+//! 
+//! This module is only available with the `async` feature enabled.
+//! 
+//! ```rust
+//! # use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! # use dw3xxx::ll::{spi::asynch, reg::SYS_CFG};
+//! # let mut spi = Mock::new(&[
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b01000000, 0b01000000]),
+//! #     Transaction::read_vec(vec![0x88, 0x06, 0x00, 0x00]),
+//! #     Transaction::transaction_end(),
+//! #     Transaction::transaction_start(),
+//! #     Transaction::write_vec(vec![0b11000000, 0b01000000]),
+//! #     Transaction::write_vec(vec![0x89, 0x0E, 0x00, 0x00]),
+//! #     Transaction::transaction_end(),
+//! # ]);
+//! # embassy_futures::block_on(async {
+//! // Enable frame filtering and automatic acknowledgement with a single read-modify-write of SYS_CFG.
+//! asynch::modify::<SYS_CFG, _>(&mut spi, |r| r.set_ffen(true).set_auto_ack(true)).await?;
+//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! # })?;
+//! # spi.done();
+//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! ```
+//! 
+
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use crate::ll::commands::Command;
+use crate::ll::reg::{Register, Writable, SPI_RD_CRC};
+
+use super::{
+    AccessMode, MaskedWriteMode, MaskedFieldWrite, CrcMode, TransactionError, IndirectAccess,
+    MAX_SUB_ADDRESS,
+    without_crc, fast_command_header, short_addressed_header, full_addressed_header, masked_write_header, crc8
+};
+
+/// Execute a fast command
+pub async fn fast_command<SPI: SpiDevice>(spi: &mut SPI, command: Command) -> Result<(), SPI::Error> {
+    spi.write(&fast_command_header(command)).await
+}
+
+/// 
+/// Perform a short addressed SPI transaction.
+/// 
+/// See [`spi::short_addressed`](super::short_addressed) for details.
+/// 
+pub async fn short_addressed<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    let header = short_addressed_header(base_address, mode);
+
+    transfer(spi, &header, mode, data).await
+}
+
+/// 
+/// Perform a full addressed SPI transaction.
+/// 
+/// See [`spi::full_addressed`](super::full_addressed) for details.
+/// 
+pub async fn full_addressed<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, sub_address: u8, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    let header = full_addressed_header(base_address, sub_address, mode);
+
+    transfer(spi, &header, mode, data).await
+}
+
+/// 
+/// Perform a masked write SPI transaction.
+/// 
+/// See [`spi::masked_write`](super::masked_write) for details.
+/// 
+pub async fn masked_write<SPI: SpiDevice>(spi: &mut SPI, base_address: u8, sub_address: u8, mode: MaskedWriteMode, data: &[u8]) -> Result<(), SPI::Error> {
+    debug_assert!(
+        mode == MaskedWriteMode::Unmasked || data.len() == 2 * mode.mask_len(),
+        "the masked write data must contain exactly one AND mask and one OR mask"
+    );
+
+    let header = masked_write_header(base_address, sub_address, mode);
+
+    spi.transaction(&mut [Operation::Write(&header), Operation::Write(data)]).await
+}
+
+/// Read an entire register from the device.
+pub async fn read<R: Register, SPI: SpiDevice>(spi: &mut SPI) -> Result<R::RegisterValue, SPI::Error> {
+    without_crc(read_crc::<R, SPI>(spi, CrcMode::Disabled).await)
+}
+
+/// Write an entire register to the device.
+pub async fn write<R: Register, SPI: SpiDevice>(spi: &mut SPI, value: R::RegisterValue) -> Result<(), SPI::Error> {
+    without_crc(write_crc::<R, SPI>(spi, CrcMode::Disabled, value).await)
+}
+
+/// 
+/// Modify a register on the device.
+/// 
+/// See [`spi::modify`](super::modify) for details.
+/// 
+pub async fn modify<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    f: impl FnOnce(&mut R::RegisterValue) -> &mut R::RegisterValue,
+) -> Result<(), SPI::Error> {
+    let mut value = read::<R, SPI>(spi).await?;
+
+    f(&mut value);
+
+    write::<R, SPI>(spi, value).await
+}
+
+/// 
+/// Perform a short addressed SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`spi::short_addressed_crc`](super::short_addressed_crc) for details.
+/// 
+pub async fn short_addressed_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let header = short_addressed_header(base_address, mode);
+
+    transfer_crc(spi, &header, mode, crc, data).await
+}
+
+/// 
+/// Perform a full addressed SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`spi::full_addressed_crc`](super::full_addressed_crc) for details.
+/// 
+pub async fn full_addressed_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    sub_address: u8,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let header = full_addressed_header(base_address, sub_address, mode);
+
+    transfer_crc(spi, &header, mode, crc, data).await
+}
+
+/// 
+/// Perform a masked write SPI transaction with the given SPI CRC mode.
+/// 
+/// See [`spi::masked_write_crc`](super::masked_write_crc) for details.
+/// 
+pub async fn masked_write_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    base_address: u8,
+    sub_address: u8,
+    mode: MaskedWriteMode,
+    crc: CrcMode,
+    data: &[u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    if crc == CrcMode::Disabled {
+        return masked_write(spi, base_address, sub_address, mode, data).await.map_err(TransactionError::BusError);
+    }
+
+    debug_assert!(
+        mode == MaskedWriteMode::Unmasked || data.len() == 2 * mode.mask_len(),
+        "the masked write data must contain exactly one AND mask and one OR mask"
+    );
+
+    let header = masked_write_header(base_address, sub_address, mode);
+    let checksum = [crc8(crc8(0, &header), data)];
+
+    spi.transaction(&mut [Operation::Write(&header), Operation::Write(data), Operation::Write(&checksum)]).await
+        .map_err(TransactionError::BusError)
+}
+
+/// Performs a transfer with the given SPI CRC mode, appending the CRC-8 to writes and checking reads against `SPI_RD_CRC`.
+async fn transfer_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    header: &[u8],
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    match (mode, crc) {
+        (_, CrcMode::Disabled) | (AccessMode::Read, CrcMode::Write) => {
+            transfer(spi, header, mode, data).await.map_err(TransactionError::BusError)
+        },
+        (AccessMode::Write, _) => {
+            let checksum = [crc8(crc8(0, header), data)];
+
+            spi.transaction(&mut [Operation::Write(header), Operation::Write(data), Operation::Write(&checksum)]).await
+                .map_err(TransactionError::BusError)
+        },
+        (AccessMode::Read, CrcMode::WriteRead) => {
+            transfer(spi, header, mode, data).await.map_err(TransactionError::BusError)?;
+
+            let mut checksum = [0];
+            full_addressed(spi, SPI_RD_CRC::BASE_ADDRESS, SPI_RD_CRC::SUB_ADDRESS, AccessMode::Read, &mut checksum).await
+                .map_err(TransactionError::BusError)?;
+
+            match checksum[0] == crc8(crc8(0, header), data) {
+                true  => Ok(()),
+                false => Err(TransactionError::CrcError)
+            }
+        },
+    }
+}
+
+/// 
+/// Write a single field of a register on the device with a masked write transaction.
+/// 
+/// See [`spi::modify_field`](super::modify_field) for details.
+/// 
+pub async fn modify_field<F: Writable, SPI: SpiDevice>(spi: &mut SPI, value: F::Value) -> Result<(), SPI::Error> {
+    let write = MaskedFieldWrite::new::<F>(value);
+
+    masked_write(spi, F::Register::BASE_ADDRESS, write.sub_address(), write.mode(), write.masks()).await
+}
+
+/// 
+/// Perform an SPI transaction through one of the indirect pointers.
+/// 
+/// See [`spi::indirect`](super::indirect) for details.
+/// 
+pub async fn indirect<SPI: SpiDevice>(spi: &mut SPI, access: IndirectAccess, mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    without_crc(indirect_crc(spi, access, mode, CrcMode::Disabled, data).await)
+}
+
+/// 
+/// Read an entire register from the device with the given SPI CRC mode.
+/// 
+/// See [`spi::read_crc`](super::read_crc) for details.
+/// 
+pub async fn read_crc<R: Register, SPI: SpiDevice>(spi: &mut SPI, crc: CrcMode) -> Result<R::RegisterValue, TransactionError<SPI::Error>> {
+    let mut value = R::RegisterValue::from(R::ZEROED);
+
+    register_transfer_crc::<R, SPI>(spi, AccessMode::Read, crc, value.as_mut()).await?;
+
+    Ok(value)
+}
+
+/// 
+/// Write an entire register to the device with the given SPI CRC mode.
+/// 
+/// See [`spi::write_crc`](super::write_crc) for details.
+/// 
+pub async fn write_crc<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    crc: CrcMode,
+    mut value: R::RegisterValue,
+) -> Result<(), TransactionError<SPI::Error>> {
+    register_transfer_crc::<R, SPI>(spi, AccessMode::Write, crc, value.as_mut()).await
+}
+
+/// 
+/// Perform an SPI transaction through one of the indirect pointers with the given SPI CRC mode.
+/// 
+/// See [`spi::indirect_crc`](super::indirect_crc) for details.
+/// 
+pub async fn indirect_crc<SPI: SpiDevice>(
+    spi: &mut SPI,
+    access: IndirectAccess,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    let (base, sub) = access.pointer().base_address_register();
+    full_addressed_crc(spi, base, sub, AccessMode::Write, crc, &mut [access.base_address()]).await?;
+
+    let (base, sub) = access.pointer().offset_register();
+    full_addressed_crc(spi, base, sub, AccessMode::Write, crc, &mut access.offset().to_le_bytes()).await?;
+
+    short_addressed_crc(spi, access.pointer().data_register(), mode, crc, data).await
+}
+
+/// Sends a transaction header followed by the transaction data in the direction given by the access mode.
+async fn transfer<SPI: SpiDevice>(spi: &mut SPI, header: &[u8], mode: AccessMode, data: &mut [u8]) -> Result<(), SPI::Error> {
+    match mode {
+        AccessMode::Read  => spi.transaction(&mut [Operation::Write(header), Operation::Read(data)]).await,
+        AccessMode::Write => spi.transaction(&mut [Operation::Write(header), Operation::Write(data)]).await,
+    }
+}
+
+/// Performs a short or full addressed transaction on an entire register depending on its sub-address.
+async fn register_transfer_crc<R: Register, SPI: SpiDevice>(
+    spi: &mut SPI,
+    mode: AccessMode,
+    crc: CrcMode,
+    data: &mut [u8],
+) -> Result<(), TransactionError<SPI::Error>> {
+    const {
+        assert!(R::SUB_ADDRESS <= MAX_SUB_ADDRESS, "the register's sub-address is too large for a full addressed transaction");
+    }
+
+    match R::SUB_ADDRESS {
+        0 => short_addressed_crc(spi, R::BASE_ADDRESS, mode, crc, data).await,
+        _ => full_addressed_crc(spi, R::BASE_ADDRESS, R::SUB_ADDRESS, mode, crc, data).await,
+    }
+}